@@ -0,0 +1,215 @@
+use crate::{
+    conics::{
+        conic::{classify_conic, symmetrized, ConicType, GeneralConic},
+        form::ConicForm,
+    },
+    forms::canonical::principal_reduction,
+    math::affine2d::{
+        primitives::{Point2, Vector2},
+        transforms::*,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// A conic with its principal axes, where along the axes it is x² / a² ± y² / b² = 1,
+// y = x² / 4f for parabolas, and the corresponding degenerate forms
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanonicalConic {
+    kind: ConicType,
+    conic: GeneralConic,
+    transform: AffineTransform2,
+    center: Point2,
+    axes: [Vector2; 2],
+    semi_axes: [Option<f64>; 2],
+    focal_length: Option<f64>,
+}
+
+impl CanonicalConic {
+    pub fn kind(&self) -> ConicType {
+        self.kind
+    }
+
+    // The conic in principal axes coordinates
+    pub fn conic(&self) -> GeneralConic {
+        self.conic
+    }
+
+    // Rigid transform mapping canonical coordinates to the coordinates of the conic
+    pub fn transform(&self) -> AffineTransform2 {
+        self.transform
+    }
+
+    // Center of central conics, vertex of parabolas and the point of the line closest
+    // to the origin otherwise
+    pub fn center(&self) -> Point2 {
+        self.center
+    }
+
+    pub fn axes(&self) -> [Vector2; 2] {
+        self.axes
+    }
+
+    // As for quadrics, 1 / √|λ| along each axis with a nonzero square λ for central
+    // conics with a nonzero constant and None otherwise. Hyperbolas have the transverse
+    // axis first and the conjugate one b, which they do not cross, second. For parallel
+    // lines the first one is half their distance.
+    pub fn semi_axes(&self) -> [Option<f64>; 2] {
+        self.semi_axes
+    }
+
+    // Distance from the vertex to the focus of parabolas
+    pub fn focal_length(&self) -> Option<f64> {
+        self.focal_length
+    }
+
+    // Number of separate curves of the real points, each one parametrized by point_at
+    pub fn branches(&self) -> usize {
+        match self.kind {
+            ConicType::Hyperbola
+            | ConicType::RealIntersectingLines
+            | ConicType::RealParallelLines => 2,
+            ConicType::RealEllipse
+            | ConicType::Parabola
+            | ConicType::ImaginaryIntersectingLines
+            | ConicType::CoincidentLines
+            | ConicType::SingleLine => 1,
+            _ => 0,
+        }
+    }
+
+    // The angle around ellipses, unbounded for the others, and a single value for the
+    // point of imaginary intersecting lines
+    pub fn parameter_range(&self) -> [f64; 2] {
+        match self.kind {
+            ConicType::RealEllipse => [0.0, core::f64::consts::TAU],
+            ConicType::ImaginaryIntersectingLines => [0.0, 0.0],
+            _ => [f64::NEG_INFINITY, f64::INFINITY],
+        }
+    }
+
+    // Hyperbolas use x = ±a cosh t, y = b sinh t and lines are parametrized by arc
+    // length from the point closest to the center
+    pub fn point_at(&self, branch: usize, t: f64) -> Option<Point2> {
+        if branch >= self.branches() {
+            return None;
+        }
+
+        let sign = if branch == 0 { 1.0 } else { -1.0 };
+        let [a, b] = self.semi_axes.map(|semi_axis| semi_axis.unwrap_or(0.0));
+        let [x, y] = match self.kind {
+            ConicType::RealEllipse => [a * t.cos(), b * t.sin()],
+            ConicType::Hyperbola => [sign * a * t.cosh(), b * t.sinh()],
+            ConicType::Parabola => {
+                let focal_length = self.focal_length.unwrap_or(1.0);
+                [t, t * t / (4.0 * focal_length)]
+            }
+            ConicType::RealIntersectingLines => {
+                let matrix = self.conic.conic_form_matrix();
+                let [first, second] =
+                    [matrix.at(0, 0), matrix.at(1, 1)].map(|curvature| curvature.abs().sqrt());
+                let length = first.hypot(second);
+                [t * second / length, sign * t * first / length]
+            }
+            ConicType::RealParallelLines => [sign * a, t],
+            ConicType::CoincidentLines => [0.0, t],
+            ConicType::SingleLine => [t, 0.0],
+            _ => [0.0, 0.0],
+        };
+
+        Some(self.transform * Point2::new(x, y))
+    }
+}
+
+// Principal axes reduction as for quadrics, with y along the axis of parabolas and
+// focal lengths from their canonical equation
+pub fn reduce_conic(matrix: &AffineTransform2, tolerance: f64) -> CanonicalConic {
+    let reduction = principal_reduction::<2, 3>(&symmetrized(matrix), tolerance);
+    let canonical = reduction.canonical;
+    let [x, y] = reduction.center;
+
+    CanonicalConic {
+        kind: classify_conic(matrix, tolerance),
+        conic: GeneralConic::from_matrix(canonical),
+        transform: reduction.transform(),
+        center: Point2::new(x, y),
+        axes: reduction.axes.map(|[x, y]| Vector2::new(x, y)),
+        semi_axes: reduction.semi_axes,
+        focal_length: (reduction.parabolic && reduction.rank > 0)
+            .then(|| 1.0 / (4.0 * canonical.at(0, 0))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conics::{ellipse::Ellipse, hyperbola::Hyperbola, parabola::Parabola};
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn assert_on_conic<F: ConicForm>(form: &F, canonical: &CanonicalConic) {
+        for branch in 0..canonical.branches() {
+            for t in [-1.5, 0.0, 0.4, 2.0] {
+                let point = canonical.point_at(branch, t).unwrap();
+                assert!(form.conic_form_value(point).abs() < 1e-12);
+            }
+        }
+        assert!(canonical.point_at(canonical.branches(), 0.0).is_none());
+    }
+
+    #[test]
+    fn ellipses_reduce_to_their_center_and_radii() {
+        let ellipse = Ellipse::new(Point2::new(1.0, -0.5), [3.0, 1.0], 0.4);
+        let canonical = reduce_conic(&ellipse.conic_form_matrix(), TOLERANCE);
+
+        assert_eq!(canonical.kind(), ConicType::RealEllipse);
+        assert!((canonical.center() - ellipse.center()).length() < 1e-12);
+        for (semi_axis, radius) in canonical.semi_axes().iter().zip([1.0, 3.0]) {
+            assert!((semi_axis.unwrap() - radius).abs() < 1e-12);
+        }
+        assert!(canonical.axes()[0].cross(canonical.axes()[1]) > 0.0);
+        assert_on_conic(&ellipse, &canonical);
+    }
+
+    #[test]
+    fn hyperbolas_have_two_branches() {
+        let hyperbola = Hyperbola::new(Point2::new(-2.0, 1.0), [2.0, 0.5], -0.7);
+        let canonical = reduce_conic(&hyperbola.conic_form_matrix(), TOLERANCE);
+
+        assert_eq!(canonical.kind(), ConicType::Hyperbola);
+        assert_eq!(canonical.branches(), 2);
+        assert!((canonical.center() - hyperbola.center()).length() < 1e-12);
+        assert!((canonical.semi_axes()[0].unwrap() - 2.0).abs() < 1e-12);
+        assert!((canonical.semi_axes()[1].unwrap() - 0.5).abs() < 1e-12);
+        assert_on_conic(&hyperbola, &canonical);
+    }
+
+    #[test]
+    fn parabolas_keep_their_vertex_and_focal_length() {
+        let parabola = Parabola::new(Point2::new(0.5, 2.0), 0.3, 1.1);
+        let canonical = reduce_conic(&parabola.conic_form_matrix(), TOLERANCE);
+
+        assert_eq!(canonical.kind(), ConicType::Parabola);
+        assert!((canonical.center() - parabola.vertex()).length() < 1e-12);
+        assert!((canonical.focal_length().unwrap() - 0.3).abs() < 1e-12);
+        assert!((canonical.axes()[1] - rotate(1.1) * Vector2::new(0.0, 1.0)).length() < 1e-12);
+        assert_on_conic(&parabola, &canonical);
+    }
+
+    #[test]
+    fn line_pairs_are_parametrized_by_branch() {
+        // x² - 4y² = 0 and (x - 1)(x + 3) = 0
+        let crossing = GeneralConic::from_coefficients([1.0, -4.0, 0.0, 0.0, 0.0, 0.0]);
+        let parallel = GeneralConic::from_coefficients([1.0, 0.0, 0.0, 2.0, 0.0, -3.0]);
+
+        let canonical = reduce_conic(&crossing.conic_form_matrix(), TOLERANCE);
+        assert_eq!(canonical.kind(), ConicType::RealIntersectingLines);
+        assert_on_conic(&crossing, &canonical);
+
+        let canonical = reduce_conic(&parallel.conic_form_matrix(), TOLERANCE);
+        assert_eq!(canonical.kind(), ConicType::RealParallelLines);
+        assert!((canonical.semi_axes()[0].unwrap() - 2.0).abs() < 1e-12);
+        assert_on_conic(&parallel, &canonical);
+    }
+}
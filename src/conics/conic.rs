@@ -0,0 +1,239 @@
+use crate::{
+    conics::form::ConicForm,
+    error::Result,
+    forms::quadric::{balanced, homogeneous_scale, inertia},
+    math::affine2d::{
+        primitives::{Point2, Vector2},
+        transforms::*,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConicType {
+    RealEllipse,
+    ImaginaryEllipse,
+    Hyperbola,
+    Parabola,
+    RealIntersectingLines,
+    ImaginaryIntersectingLines,
+    RealParallelLines,
+    ImaginaryParallelLines,
+    CoincidentLines,
+    SingleLine,
+    EmptySet,
+    WholePlane,
+}
+
+pub(crate) fn symmetrized(matrix: &AffineTransform2) -> AffineTransform2 {
+    let mut symmetric = *matrix;
+
+    for row in 0..3 {
+        for col in 0..3 {
+            *symmetric.at_mut(row, col) = 0.5 * (matrix.at(row, col) + matrix.at(col, row));
+        }
+    }
+
+    symmetric
+}
+
+// The matrix of the conic moved by the rigid motion taking the origin to the center
+// and the x axis to the given angle
+pub(crate) fn placed(matrix: AffineTransform2, center: Point2, angle: f64) -> AffineTransform2 {
+    let inverse = rotate(-angle) * translate(-Vector2::to_point(center));
+    inverse.transpose() * matrix * inverse
+}
+
+// Affine classification by rank and signature of the symmetric matrix and its
+// quadratic 2x2 block, in the same way as for quadrics, balancing the homogeneous
+// coordinate so that circles of any radius are ellipses
+pub fn classify_conic(matrix: &AffineTransform2, tolerance: f64) -> ConicType {
+    let symmetric = symmetrized(matrix);
+    let symmetric = balanced(&symmetric, homogeneous_scale(&symmetric));
+    let (values3, _) = symmetric.symmetric_eigen();
+    let (values2, _) = symmetric.submatrix::<2, 2>(0, 0).symmetric_eigen();
+
+    let scale = values3
+        .iter()
+        .fold(0.0_f64, |max, value| max.max(value.abs()));
+    if scale == 0.0 {
+        return ConicType::WholePlane;
+    }
+
+    let threshold = tolerance * scale;
+    let (mut positive2, mut negative2) = inertia(&values2, threshold);
+    let (mut positive3, mut negative3) = inertia(&values3, threshold);
+
+    if negative2 > positive2 || (negative2 == positive2 && negative3 > positive3) {
+        core::mem::swap(&mut positive2, &mut negative2);
+        core::mem::swap(&mut positive3, &mut negative3);
+    }
+
+    match (positive2 + negative2, positive3 + negative3) {
+        (2, 3) => match (negative2, negative3) {
+            (0, 1) => ConicType::RealEllipse,
+            (0, _) => ConicType::ImaginaryEllipse,
+            _ => ConicType::Hyperbola,
+        },
+        (2, _) if negative2 == 0 => ConicType::ImaginaryIntersectingLines,
+        (2, _) => ConicType::RealIntersectingLines,
+        (1, 3) => ConicType::Parabola,
+        (1, 2) if negative3 == 1 => ConicType::RealParallelLines,
+        (1, 2) => ConicType::ImaginaryParallelLines,
+        (1, _) => ConicType::CoincidentLines,
+        (_, 2..) => ConicType::SingleLine,
+        (_, 1) => ConicType::EmptySet,
+        _ => ConicType::WholePlane,
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneralConic {
+    matrix: AffineTransform2,
+}
+
+impl GeneralConic {
+    // Coefficients of Ax² + By² + Cxy + Dx + Ey + F = 0
+    pub fn from_coefficients(coefficients: [f64; 6]) -> GeneralConic {
+        let [a, b, c, d, e, f] = coefficients;
+
+        GeneralConic {
+            matrix: AffineTransform2::from_data([
+                [a, 0.5 * c, 0.5 * d],
+                [0.5 * c, b, 0.5 * e],
+                [0.5 * d, 0.5 * e, f],
+            ]),
+        }
+    }
+
+    pub fn from_matrix(matrix: AffineTransform2) -> GeneralConic {
+        GeneralConic {
+            matrix: symmetrized(&matrix),
+        }
+    }
+
+    pub fn from_form<F: ConicForm + ?Sized>(form: &F) -> GeneralConic {
+        Self::from_matrix(form.conic_form_matrix())
+    }
+
+    pub fn coefficients(&self) -> [f64; 6] {
+        let m = &self.matrix;
+
+        [
+            m.at(0, 0),
+            m.at(1, 1),
+            2.0 * m.at(0, 1),
+            2.0 * m.at(0, 2),
+            2.0 * m.at(1, 2),
+            m.at(2, 2),
+        ]
+    }
+
+    // The image of the conic under the transform, T^-T C T^-1
+    pub fn transformed(&self, transform: &AffineTransform2) -> Result<GeneralConic> {
+        let inverse = transform.try_inverse()?;
+        Ok(Self::from_matrix(
+            inverse.transpose() * self.matrix * inverse,
+        ))
+    }
+}
+
+impl ConicForm for GeneralConic {
+    fn conic_form_matrix(&self) -> AffineTransform2 {
+        self.matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{conics::ellipse::Ellipse, forms::quadric::DEFAULT_CLASSIFICATION_TOLERANCE};
+
+    // One equation of every type, as coefficients of Ax² + By² + Cxy + Dx + Ey + F
+    const CASES: [([f64; 6], ConicType); 12] = [
+        ([1.0, 2.0, 0.0, 0.0, 0.0, -1.0], ConicType::RealEllipse),
+        ([1.0, 1.0, 0.0, 0.0, 0.0, 1.0], ConicType::ImaginaryEllipse),
+        ([1.0, -1.0, 0.0, 0.0, 0.0, -1.0], ConicType::Hyperbola),
+        ([1.0, 0.0, 0.0, 0.0, -1.0, 0.0], ConicType::Parabola),
+        (
+            [1.0, -4.0, 0.0, 0.0, 0.0, 0.0],
+            ConicType::RealIntersectingLines,
+        ),
+        (
+            [1.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+            ConicType::ImaginaryIntersectingLines,
+        ),
+        (
+            [1.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+            ConicType::RealParallelLines,
+        ),
+        (
+            [1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            ConicType::ImaginaryParallelLines,
+        ),
+        ([1.0, 0.0, 0.0, 0.0, 0.0, 0.0], ConicType::CoincidentLines),
+        ([0.0, 0.0, 0.0, 1.0, 0.0, -1.0], ConicType::SingleLine),
+        ([0.0, 0.0, 0.0, 0.0, 0.0, 1.0], ConicType::EmptySet),
+        ([0.0; 6], ConicType::WholePlane),
+    ];
+
+    fn classify<F: ConicForm>(form: &F) -> ConicType {
+        classify_conic(&form.conic_form_matrix(), DEFAULT_CLASSIFICATION_TOLERANCE)
+    }
+
+    #[test]
+    fn every_type_is_recognized() {
+        for (coefficients, kind) in CASES {
+            assert_eq!(
+                classify(&GeneralConic::from_coefficients(coefficients)),
+                kind
+            );
+
+            let negated = coefficients.map(|coefficient| -3.0 * coefficient);
+            assert_eq!(classify(&GeneralConic::from_coefficients(negated)), kind);
+        }
+    }
+
+    #[test]
+    fn types_are_affine_invariants() {
+        let transforms = [
+            translate(Vector2::new(0.5, -2.0)) * rotate(0.7),
+            rotate(2.1) * scale(3.0, 0.5),
+            translate(Vector2::new(1e6, -3e6)) * rotate(0.4) * scale(1e6, 1e6),
+            translate(Vector2::new(1e-6, -2e-6)) * rotate(-0.9) * scale(1e-6, 1e-6),
+        ];
+
+        for (coefficients, kind) in CASES {
+            for transform in &transforms {
+                let moved = GeneralConic::from_coefficients(coefficients)
+                    .transformed(transform)
+                    .unwrap();
+                assert_eq!(
+                    classify(&moved),
+                    kind,
+                    "{:?} moved by {:?}",
+                    kind,
+                    transform
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn circles_of_any_size_are_ellipses() {
+        for radius in [1e-9, 1e-6, 1e-3, 1.0, 1e4, 1e5, 1e6, 1e9] {
+            let circle = Ellipse::with_radii(radius, radius);
+            assert_eq!(
+                classify(&circle),
+                ConicType::RealEllipse,
+                "radius {}",
+                radius
+            );
+
+            let center = Point2::new(2.0 * radius, -radius);
+            let placed = Ellipse::new(center, [radius; 2], 0.3);
+            assert_eq!(classify(&placed), ConicType::RealEllipse);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    conics::{conic::placed, form::ConicForm},
+    math::affine2d::{primitives::Point2, transforms::*},
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// Points with x² / a² + y² / b² = 1 in the frame of the ellipse, turned by the angle
+// from the x axis and centered at the center
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ellipse {
+    center: Point2,
+    angle: f64,
+    curvatures: [f64; 2],
+}
+
+impl Ellipse {
+    pub fn with_curvatures(a: f64, b: f64) -> Ellipse {
+        Ellipse {
+            center: Point2::new(0.0, 0.0),
+            angle: 0.0,
+            curvatures: [a, b],
+        }
+    }
+
+    pub fn with_radii(rx: f64, ry: f64) -> Ellipse {
+        Self::with_curvatures(1.0 / (rx * rx), 1.0 / (ry * ry))
+    }
+
+    pub fn new(center: Point2, radii: [f64; 2], angle: f64) -> Ellipse {
+        Ellipse {
+            center,
+            angle,
+            curvatures: radii.map(|radius| 1.0 / (radius * radius)),
+        }
+    }
+
+    pub fn center(&self) -> Point2 {
+        self.center
+    }
+
+    pub fn angle(&self) -> f64 {
+        self.angle
+    }
+
+    pub fn radii(&self) -> [f64; 2] {
+        self.curvatures.map(|curvature| 1.0 / curvature.sqrt())
+    }
+}
+
+impl ConicForm for Ellipse {
+    fn conic_form_matrix(&self) -> AffineTransform2 {
+        let [a, b] = self.curvatures;
+        placed(
+            AffineTransform2::diagonal(&[a, b, -1.0]),
+            self.center,
+            self.angle,
+        )
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    conics::{
+        canonical::{reduce_conic, CanonicalConic},
+        conic::{classify_conic, ConicType},
+    },
+    forms::quadric::DEFAULT_CLASSIFICATION_TOLERANCE,
+    math::affine2d::{
+        primitives::{Point2, Vector2},
+        transforms::AffineTransform2,
+    },
+};
+use alloc::boxed::Box;
+
+// Conics [x y 1] C [x y 1]^T = 0, the plane counterpart of QuadraticForm
+pub trait ConicForm {
+    fn conic_form_matrix(&self) -> AffineTransform2;
+
+    fn conic_form_value(&self, u: Point2) -> f64 {
+        (u.as_transpose() * self.conic_form_matrix() * u.as_matrix()).num()
+    }
+
+    fn derivative_at(&self, u: Point2) -> Vector2 {
+        let vec =
+            u.as_transpose() * (self.conic_form_matrix() + self.conic_form_matrix().transpose());
+        Vector2::new(vec.at(0, 0), vec.at(0, 1))
+    }
+
+    // Whether the point lies in the region bounded by the conic, conic included
+    fn contains(&self, u: Point2) -> bool {
+        self.conic_form_value(u) <= 0.0
+    }
+
+    fn classify(&self) -> ConicType {
+        classify_conic(&self.conic_form_matrix(), DEFAULT_CLASSIFICATION_TOLERANCE)
+    }
+
+    fn canonical_form(&self) -> CanonicalConic {
+        reduce_conic(&self.conic_form_matrix(), DEFAULT_CLASSIFICATION_TOLERANCE)
+    }
+}
+
+impl<F: ConicForm + ?Sized> ConicForm for Box<F> {
+    fn conic_form_matrix(&self) -> AffineTransform2 {
+        (**self).conic_form_matrix()
+    }
+}
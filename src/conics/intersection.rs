@@ -0,0 +1,443 @@
+use crate::{
+    conics::{
+        conic::{symmetrized, GeneralConic},
+        form::ConicForm,
+        line::Line,
+    },
+    math::{
+        affine2d::{
+            primitives::{Point2, Vector2},
+            transforms::*,
+        },
+        matrix::Matrix,
+        roots::{evaluate_polynomial, real_roots},
+    },
+};
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// Both conics are rotated by this angle before eliminating y, so that no two
+// intersections share the same x and the y² terms do not vanish, except by coincidence
+const GENERIC_ANGLE: f64 = 0.5772156649015329;
+
+const POLISH_ITERATIONS: usize = 4;
+
+// A coefficient computed from terms of this magnitude is zero up to rounding
+fn cleared(value: f64, magnitude: f64, tolerance: f64) -> f64 {
+    if value.abs() <= tolerance * magnitude {
+        0.0
+    } else {
+        value
+    }
+}
+
+fn largest_entry(matrix: &AffineTransform2) -> f64 {
+    matrix
+        .raw()
+        .iter()
+        .fold(0.0_f64, |max, entry| max.max(entry.abs()))
+}
+
+fn absolute(matrix: &AffineTransform2) -> AffineTransform2 {
+    let mut absolute = *matrix;
+
+    for row in 0..3 {
+        for col in 0..3 {
+            *absolute.at_mut(row, col) = matrix.at(row, col).abs();
+        }
+    }
+
+    absolute
+}
+
+fn bilinear(matrix: &AffineTransform2, u: [f64; 3], v: [f64; 3]) -> f64 {
+    let (u, v) = (Matrix::from_data([u]), Matrix::from_data([v]));
+    (u * *matrix * v.transpose()).num()
+}
+
+// Points of the line where the value of the conic along it, a t² + 2 b t + c, vanishes,
+// or None when the line lies on the conic
+pub fn intersect_line<F: ConicForm + ?Sized>(
+    form: &F,
+    line: &Line,
+    tolerance: f64,
+) -> Option<Vec<Point2>> {
+    let matrix = symmetrized(&form.conic_form_matrix());
+    let magnitudes = absolute(&matrix);
+    let point = [line.point.at(0), line.point.at(1), 1.0];
+    let direction = [line.direction.at(0), line.direction.at(1), 0.0];
+
+    let term = |u: [f64; 3], v: [f64; 3]| {
+        cleared(
+            bilinear(&matrix, u, v),
+            bilinear(&magnitudes, u.map(f64::abs), v.map(f64::abs)),
+            tolerance,
+        )
+    };
+    let a = term(direction, direction);
+    let b = term(direction, point);
+    let c = term(point, point);
+
+    if a == 0.0 && b == 0.0 && c == 0.0 {
+        return None;
+    }
+
+    Some(
+        real_roots(&[c, 2.0 * b, a], tolerance)
+            .into_iter()
+            .map(|t| line.at(t))
+            .collect(),
+    )
+}
+
+// Polynomials in x of the coefficients of a y² + b(x) y + c(x), in increasing degree
+struct VerticalCoefficients {
+    square: f64,
+    linear: [f64; 2],
+    constant: [f64; 3],
+}
+
+impl VerticalCoefficients {
+    fn new(matrix: &AffineTransform2) -> VerticalCoefficients {
+        VerticalCoefficients {
+            square: matrix.at(1, 1),
+            linear: [2.0 * matrix.at(1, 2), 2.0 * matrix.at(0, 1)],
+            constant: [matrix.at(2, 2), 2.0 * matrix.at(0, 2), matrix.at(0, 0)],
+        }
+    }
+
+    // The values of y on the conic at x, or None when the whole vertical line is on it
+    fn roots_at(
+        &self,
+        x: f64,
+        magnitudes: &VerticalCoefficients,
+        tolerance: f64,
+    ) -> Option<Vec<f64>> {
+        let linear = cleared(
+            evaluate_polynomial(&self.linear, x),
+            evaluate_polynomial(&magnitudes.linear, x.abs()),
+            tolerance,
+        );
+        let constant = cleared(
+            evaluate_polynomial(&self.constant, x),
+            evaluate_polynomial(&magnitudes.constant, x.abs()),
+            tolerance,
+        );
+
+        if self.square == 0.0 && linear == 0.0 && constant == 0.0 {
+            return None;
+        }
+
+        Some(real_roots(&[constant, linear, self.square], tolerance))
+    }
+}
+
+fn product(first: &[f64], second: &[f64]) -> [f64; 5] {
+    let mut product = [0.0; 5];
+
+    for (i, &a) in first.iter().enumerate() {
+        for (j, &b) in second.iter().enumerate() {
+            product[i + j] += a * b;
+        }
+    }
+
+    product
+}
+
+fn combination(first: [f64; 5], second: [f64; 5], sign: f64) -> [f64; 5] {
+    core::array::from_fn(|i| first[i] + sign * second[i])
+}
+
+// Resultant of the two conics as quadratics in y, (a2 b0 - b2 a0)² - (a2 b1 - b2 a1)
+// (a1 b0 - b1 a0), a polynomial of degree 4 in x that vanishes at the x of the common
+// points. The sign gives the same expression on the magnitudes of the terms.
+fn resultant(first: &VerticalCoefficients, second: &VerticalCoefficients, sign: f64) -> [f64; 5] {
+    let square_constant = combination(
+        product(&[first.square], &second.constant),
+        product(&[second.square], &first.constant),
+        -sign,
+    );
+    let square_linear = combination(
+        product(&[first.square], &second.linear),
+        product(&[second.square], &first.linear),
+        -sign,
+    );
+    let linear_constant = combination(
+        product(&first.linear, &second.constant),
+        product(&second.linear, &first.constant),
+        -sign,
+    );
+
+    combination(
+        product(&square_constant[..3], &square_constant[..3]),
+        product(&square_linear[..2], &linear_constant[..4]),
+        -sign,
+    )
+}
+
+// Distance of the point to the conic to first order, or the value at its critical
+// points
+fn residual(conic: &GeneralConic, point: Point2) -> f64 {
+    let value = conic.conic_form_value(point).abs();
+    let gradient = conic.derivative_at(point).length();
+
+    if gradient > 0.0 {
+        value / gradient
+    } else {
+        value
+    }
+}
+
+// Newton steps on both equations, which refine transversal intersections, while
+// tangential ones where the gradients are parallel are left as found
+fn polish(conics: &[GeneralConic; 2], mut point: Point2) -> Point2 {
+    let size = |point: Point2| residual(&conics[0], point) + residual(&conics[1], point);
+
+    for _ in 0..POLISH_ITERATIONS {
+        let values = conics.map(|conic| conic.conic_form_value(point));
+        let gradients = conics.map(|conic| conic.derivative_at(point));
+        let determinant = gradients[0].cross(gradients[1]);
+        if determinant.abs() <= f64::EPSILON.sqrt() * gradients[0].length() * gradients[1].length()
+        {
+            break;
+        }
+
+        let step = Vector2::new(
+            values[0] * gradients[1].at(1) - values[1] * gradients[0].at(1),
+            values[1] * gradients[0].at(0) - values[0] * gradients[1].at(0),
+        ) * (1.0 / determinant);
+        let candidate = point - step;
+
+        if size(candidate) < size(point) {
+            point = candidate;
+        } else {
+            break;
+        }
+    }
+
+    point
+}
+
+// The common points of two conics, or None when they share a line or a whole conic or
+// either one is the whole plane. y is eliminated into a quartic in x whose roots are
+// lifted to the points of one conic that also lie on the other, and tangential
+// intersections, which are double roots, are only accurate to about the square root
+// of the tolerance.
+pub fn intersect_conics<F: ConicForm + ?Sized, G: ConicForm + ?Sized>(
+    first: &F,
+    second: &G,
+    tolerance: f64,
+) -> Option<Vec<Point2>> {
+    let normalized = |matrix: AffineTransform2| {
+        let mut matrix = symmetrized(&matrix);
+        let largest = largest_entry(&matrix);
+        if largest > 0.0 {
+            for row in 0..3 {
+                for col in 0..3 {
+                    *matrix.at_mut(row, col) /= largest;
+                }
+            }
+        }
+        matrix
+    };
+    let matrices = [
+        normalized(first.conic_form_matrix()),
+        normalized(second.conic_form_matrix()),
+    ];
+
+    if matrices.iter().any(|matrix| largest_entry(matrix) == 0.0) {
+        return None;
+    }
+
+    // Conics without quadratic terms are lines, or empty
+    for (index, matrix) in matrices.iter().enumerate() {
+        let quadratic = matrix.submatrix::<2, 2>(0, 0);
+        if quadratic
+            .raw()
+            .iter()
+            .all(|&entry| entry.abs() <= tolerance)
+        {
+            let line = Line::from_homogeneous([
+                2.0 * matrix.at(0, 2),
+                2.0 * matrix.at(1, 2),
+                matrix.at(2, 2),
+            ]);
+            return match line {
+                Ok(line) => intersect_line(
+                    &GeneralConic::from_matrix(matrices[1 - index]),
+                    &line,
+                    tolerance,
+                ),
+                Err(_) => Some(Vec::new()),
+            };
+        }
+    }
+
+    let rotation = rotate(GENERIC_ANGLE);
+    let rotated = matrices.map(|matrix| rotation.transpose() * matrix * rotation);
+    let coefficients = rotated.map(|matrix| VerticalCoefficients::new(&matrix));
+    let magnitudes = rotated.map(|matrix| VerticalCoefficients::new(&absolute(&matrix)));
+    let conics = rotated.map(GeneralConic::from_matrix);
+
+    let values = resultant(&coefficients[0], &coefficients[1], 1.0);
+    let sizes = resultant(&magnitudes[0], &magnitudes[1], -1.0);
+    let polynomial: [f64; 5] = core::array::from_fn(|i| cleared(values[i], sizes[i], tolerance));
+    if polynomial.iter().all(|&coefficient| coefficient == 0.0) {
+        return None;
+    }
+
+    // Lifts from the conic with the larger y² term, which has fewer spurious roots
+    let order = if rotated[0].at(1, 1).abs() >= rotated[1].at(1, 1).abs() {
+        [0, 1]
+    } else {
+        [1, 0]
+    };
+    let separation = tolerance.sqrt();
+
+    let mut points: Vec<Point2> = Vec::new();
+    for x in real_roots(&polynomial, tolerance) {
+        let ys = order
+            .iter()
+            .find_map(|&index| coefficients[index].roots_at(x, &magnitudes[index], tolerance))
+            .unwrap_or_default();
+
+        for y in ys {
+            let candidate = Point2::new(x, y);
+            let near = |index: usize| {
+                residual(&conics[index], candidate)
+                    <= separation * (1.0 + Vector2::to_point(candidate).length())
+            };
+            if !(near(0) && near(1)) {
+                continue;
+            }
+
+            let point = polish(&conics, candidate);
+            let duplicate = points.iter().any(|&other| {
+                (point - other).length() <= separation * (1.0 + Vector2::to_point(point).length())
+            });
+            if !duplicate {
+                points.push(point);
+            }
+        }
+    }
+
+    Some(points.into_iter().map(|point| rotation * point).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conics::{ellipse::Ellipse, parabola::Parabola};
+
+    const TOLERANCE: f64 = 1e-12;
+
+    fn circle(center: Point2, radius: f64) -> Ellipse {
+        Ellipse::new(center, [radius, radius], 0.0)
+    }
+
+    fn assert_points(points: &[Point2], expected: &[Point2], accuracy: f64) {
+        assert_eq!(points.len(), expected.len(), "{:?}", points);
+        for point in expected {
+            assert!(
+                points
+                    .iter()
+                    .any(|&other| (other - *point).length() < accuracy),
+                "{:?}",
+                points
+            );
+        }
+    }
+
+    #[test]
+    fn lines_meet_conics_in_up_to_two_points() {
+        let parabola = Parabola::with_focal_length(0.25);
+        let line = Line::new(Point2::new(0.0, 1.0), Vector2::new(1.0, 0.0));
+        let points = intersect_line(&parabola, &line, TOLERANCE).unwrap();
+        assert_points(
+            &points,
+            &[Point2::new(-1.0, 1.0), Point2::new(1.0, 1.0)],
+            1e-12,
+        );
+
+        let missing = Line::new(Point2::new(0.0, -1.0), Vector2::new(1.0, 0.0));
+        assert!(intersect_line(&parabola, &missing, TOLERANCE)
+            .unwrap()
+            .is_empty());
+
+        let axes = GeneralConic::from_coefficients([0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+        let on_axis = Line::new(Point2::new(0.0, 0.0), Vector2::new(0.0, 1.0));
+        assert!(intersect_line(&axes, &on_axis, TOLERANCE).is_none());
+    }
+
+    #[test]
+    fn circle_and_ellipse_meet_in_four_points() {
+        let ellipse = Ellipse::with_radii(2.0, 0.5);
+        let points =
+            intersect_conics(&circle(Point2::new(0.0, 0.0), 1.0), &ellipse, TOLERANCE).unwrap();
+
+        // x² + y² = 1 and x² / 4 + 4 y² = 1 give x² = 4 / 5 and y² = 1 / 5
+        let (x, y) = ((0.8_f64).sqrt(), (0.2_f64).sqrt());
+        let expected = [(x, y), (-x, y), (x, -y), (-x, -y)].map(|(x, y)| Point2::new(x, y));
+        assert_points(&points, &expected, 1e-12);
+    }
+
+    #[test]
+    fn touching_circles_meet_once() {
+        let points = intersect_conics(
+            &circle(Point2::new(0.0, 0.0), 1.0),
+            &circle(Point2::new(2.0, 0.0), 1.0),
+            TOLERANCE,
+        )
+        .unwrap();
+
+        // Tangential intersections are only accurate to the square root of the tolerance
+        assert_points(&points, &[Point2::new(1.0, 0.0)], 1e-5);
+    }
+
+    #[test]
+    fn disjoint_and_identical_conics() {
+        let unit = circle(Point2::new(0.0, 0.0), 1.0);
+
+        let far = intersect_conics(&unit, &circle(Point2::new(5.0, 0.0), 1.0), TOLERANCE);
+        assert!(far.unwrap().is_empty());
+        let inside = intersect_conics(&unit, &circle(Point2::new(0.1, 0.0), 0.5), TOLERANCE);
+        assert!(inside.unwrap().is_empty());
+
+        assert!(intersect_conics(&unit, &unit, TOLERANCE).is_none());
+    }
+
+    #[test]
+    fn degenerate_conics_meet_circles() {
+        let unit = circle(Point2::new(0.0, 0.0), 1.0);
+
+        // The coordinate axes, xy = 0
+        let axes = GeneralConic::from_coefficients([0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+        let points = intersect_conics(&axes, &unit, TOLERANCE).unwrap();
+        let expected =
+            [(1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (0.0, -1.0)].map(|(x, y)| Point2::new(x, y));
+        assert_points(&points, &expected, 1e-12);
+
+        // The line x = 0.6 has no quadratic terms
+        let line = GeneralConic::from_coefficients([0.0, 0.0, 0.0, 1.0, 0.0, -0.6]);
+        let points = intersect_conics(&unit, &line, TOLERANCE).unwrap();
+        assert_points(
+            &points,
+            &[Point2::new(0.6, 0.8), Point2::new(0.6, -0.8)],
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn placed_conics_meet_where_both_vanish() {
+        let ellipse = Ellipse::new(Point2::new(1.0, -0.5), [3.0, 1.0], 0.4);
+        let parabola = Parabola::new(Point2::new(0.5, -1.0), 0.3, -0.2);
+        let points = intersect_conics(&ellipse, &parabola, TOLERANCE).unwrap();
+
+        assert!(!points.is_empty());
+        for point in points {
+            assert!(residual(&GeneralConic::from_form(&ellipse), point) < 1e-10);
+            assert!(residual(&GeneralConic::from_form(&parabola), point) < 1e-10);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    error::{Error, Result},
+    math::affine2d::primitives::{Point2, Vector2},
+};
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line {
+    pub point: Point2,
+    pub direction: Vector2,
+}
+
+impl Line {
+    pub fn new(point: Point2, direction: Vector2) -> Line {
+        Line { point, direction }
+    }
+
+    pub fn through(first: Point2, second: Point2) -> Line {
+        Self::new(first, second - first)
+    }
+
+    // The line a x + b y + c = 0, with the direction turned clockwise from (a, b) and
+    // the point closest to the origin
+    pub fn from_homogeneous(coefficients: [f64; 3]) -> Result<Line> {
+        let [a, b, c] = coefficients;
+        let normal = Vector2::new(a, b);
+        let length = normal * normal;
+        if length == 0.0 || !length.is_finite() {
+            return Err(Error::ZeroVector);
+        }
+
+        Ok(Line {
+            point: Point2::new(0.0, 0.0) + normal * (-c / length),
+            direction: -normal.perpendicular(),
+        })
+    }
+
+    // Coefficients of a x + b y + c = 0, positive to the left of the direction
+    pub fn homogeneous(&self) -> [f64; 3] {
+        let normal = self.direction.perpendicular();
+        [
+            normal.at(0),
+            normal.at(1),
+            -(normal * Vector2::to_point(self.point)),
+        ]
+    }
+
+    pub fn at(&self, t: f64) -> Point2 {
+        self.point + self.direction * t
+    }
+
+    // Signed distance, positive to the left of the direction
+    pub fn distance_to(&self, point: Point2) -> f64 {
+        self.direction.cross(point - self.point) / self.direction.length()
+    }
+}
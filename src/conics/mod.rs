@@ -0,0 +1,9 @@
+pub mod canonical;
+pub mod conic;
+pub mod ellipse;
+pub mod form;
+pub mod hyperbola;
+pub mod intersection;
+pub mod line;
+pub mod parabola;
+pub mod tangent;
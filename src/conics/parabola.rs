@@ -0,0 +1,55 @@
+use crate::{
+    conics::{conic::placed, form::ConicForm},
+    math::affine2d::{primitives::Point2, transforms::*},
+};
+
+// Points with y = x² / 4f in the frame of the parabola, so that it opens along its y
+// axis with the focus at distance f from the vertex
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parabola {
+    vertex: Point2,
+    angle: f64,
+    focal_length: f64,
+}
+
+impl Parabola {
+    pub fn with_focal_length(focal_length: f64) -> Parabola {
+        Parabola {
+            vertex: Point2::new(0.0, 0.0),
+            angle: 0.0,
+            focal_length,
+        }
+    }
+
+    pub fn new(vertex: Point2, focal_length: f64, angle: f64) -> Parabola {
+        Parabola {
+            vertex,
+            angle,
+            focal_length,
+        }
+    }
+
+    pub fn vertex(&self) -> Point2 {
+        self.vertex
+    }
+
+    pub fn angle(&self) -> f64 {
+        self.angle
+    }
+
+    pub fn focal_length(&self) -> f64 {
+        self.focal_length
+    }
+}
+
+impl ConicForm for Parabola {
+    fn conic_form_matrix(&self) -> AffineTransform2 {
+        let mut matrix = AffineTransform2::diagonal(&[1.0 / (4.0 * self.focal_length), 0.0, 0.0]);
+
+        *matrix.at_mut(1, 2) = -0.5;
+        *matrix.at_mut(2, 1) = -0.5;
+
+        placed(matrix, self.vertex, self.angle)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    conics::{conic::symmetrized, form::ConicForm, intersection::intersect_line, line::Line},
+    error::{Error, Result},
+    math::{
+        affine2d::primitives::{Point2, Vector2},
+        matrix::Matrix,
+    },
+};
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// The polar line of the homogeneous point (x, y, w), which is a point at infinity in
+// the direction (x, y) when w is zero
+fn polar<F: ConicForm + ?Sized>(form: &F, pole: [f64; 3]) -> Result<Line> {
+    let polar = symmetrized(&form.conic_form_matrix()) * Matrix::from_data([pole]).transpose();
+    Line::from_homogeneous([polar.at(0, 0), polar.at(1, 0), polar.at(2, 0)])
+}
+
+// The line of points conjugate to the pole. For points on the conic it is the tangent
+// there, for points outside it passes through the points of contact of the tangents
+// from the pole, and it does not exist for the center of central conics.
+pub fn polar_line<F: ConicForm + ?Sized>(form: &F, pole: Point2) -> Result<Line> {
+    polar(form, [pole.at(0), pole.at(1), 1.0])
+}
+
+// The line through the point orthogonal to the gradient, which does not exist at
+// singular points such as where two lines cross
+pub fn tangent_at<F: ConicForm + ?Sized>(form: &F, point: Point2) -> Result<Line> {
+    let gradient = form.derivative_at(point);
+    if gradient.length() == 0.0 || !gradient.length().is_finite() {
+        return Err(Error::ZeroVector);
+    }
+
+    Ok(Line::new(point, gradient.perpendicular()))
+}
+
+// Tangent lines through the point, each one starting at its point of contact. The
+// contacts are where the polar line meets the conic, and a point on the conic is its
+// own contact.
+pub fn tangents_through<F: ConicForm + ?Sized>(
+    form: &F,
+    point: Point2,
+    tolerance: f64,
+) -> Vec<Line> {
+    let contacts = polar_line(form, point)
+        .ok()
+        .and_then(|polar| intersect_line(form, &polar, tolerance))
+        .unwrap_or_default();
+    let separation = tolerance.sqrt() * (1.0 + Vector2::to_point(point).length());
+
+    contacts
+        .into_iter()
+        .filter_map(|contact| {
+            if (point - contact).length() <= separation {
+                tangent_at(form, contact).ok()
+            } else {
+                Some(Line::through(contact, point))
+            }
+        })
+        .collect()
+}
+
+// Tangent lines along the direction, each one starting at its point of contact, which
+// lie on the diameter conjugate to the direction
+pub fn tangents_parallel_to<F: ConicForm + ?Sized>(
+    form: &F,
+    direction: Vector2,
+    tolerance: f64,
+) -> Vec<Line> {
+    polar(form, [direction.at(0), direction.at(1), 0.0])
+        .ok()
+        .and_then(|diameter| intersect_line(form, &diameter, tolerance))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|contact| Line::new(contact, direction))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conics::ellipse::Ellipse;
+
+    const TOLERANCE: f64 = 1e-12;
+
+    #[test]
+    fn tangents_from_an_outside_point_touch_the_circle() {
+        let circle = Ellipse::with_radii(1.0, 1.0);
+        let point = Point2::new(2.0, 0.0);
+        let tangents = tangents_through(&circle, point, TOLERANCE);
+
+        assert_eq!(tangents.len(), 2);
+        for tangent in &tangents {
+            // Contacts are at (1/2, ±√3/2), at distance 1 from the center
+            assert!((tangent.point.at(0) - 0.5).abs() < 1e-12);
+            assert!(circle.conic_form_value(tangent.point).abs() < 1e-12);
+            assert!(tangent.distance_to(point).abs() < 1e-12);
+            assert!((tangent.distance_to(Point2::new(0.0, 0.0)).abs() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn points_on_and_inside_the_conic() {
+        let ellipse = Ellipse::with_radii(2.0, 1.0);
+
+        let on = Point2::new(0.0, 1.0);
+        let tangents = tangents_through(&ellipse, on, TOLERANCE);
+        assert_eq!(tangents.len(), 1);
+        assert!(tangents[0].direction.cross(Vector2::new(1.0, 0.0)).abs() < 1e-12);
+
+        assert!(tangents_through(&ellipse, Point2::new(0.5, 0.0), TOLERANCE).is_empty());
+        assert!(polar_line(&ellipse, Point2::new(0.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn parallel_tangents_touch_on_the_conjugate_diameter() {
+        let ellipse = Ellipse::with_radii(2.0, 1.0);
+        let tangents = tangents_parallel_to(&ellipse, Vector2::new(1.0, 0.0), TOLERANCE);
+
+        assert_eq!(tangents.len(), 2);
+        for tangent in tangents {
+            assert!(tangent.point.at(0).abs() < 1e-12);
+            assert!((tangent.point.at(1).abs() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn singular_points_have_no_tangent() {
+        let axes =
+            crate::conics::conic::GeneralConic::from_coefficients([0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+
+        assert!(tangent_at(&axes, Point2::new(0.0, 0.0)).is_err());
+        assert!(tangent_at(&axes, Point2::new(1.0, 0.0)).is_ok());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::math::parse::ParseMatrixError;
+use alloc::string::String;
+use core::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    NotAPoint {
+        w: f64,
+    },
+    NotAVector {
+        w: f64,
+    },
+    SingularMatrix,
+    NotAffine,
+    NotPositiveDefinite,
+    NotPositive {
+        value: f64,
+    },
+    ZeroVector,
+    DegenerateBox,
+    Parse(ParseMatrixError),
+    InvalidMesh(String),
+    #[cfg(feature = "std")]
+    Io(String),
+    #[cfg(feature = "render")]
+    Window(String),
+    #[cfg(feature = "render")]
+    Renderer(String),
+    #[cfg(feature = "render")]
+    Shader(String),
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NotAPoint { w } => {
+                write!(formatter, "affine element with w = {} is not a point", w)
+            }
+            Error::NotAVector { w } => {
+                write!(formatter, "affine element with w = {} is not a vector", w)
+            }
+            Error::SingularMatrix => write!(formatter, "matrix is singular"),
+            Error::NotAffine => write!(formatter, "transform is not affine"),
+            Error::NotPositiveDefinite => write!(formatter, "matrix is not positive definite"),
+            Error::NotPositive { value } => write!(formatter, "{} is not positive", value),
+            Error::ZeroVector => write!(formatter, "vector has zero length"),
+            Error::DegenerateBox => {
+                write!(formatter, "bounding box has a zero or negative extent")
+            }
+            Error::Parse(error) => write!(formatter, "parsing failed: {}", error),
+            Error::InvalidMesh(message) => write!(formatter, "invalid mesh: {}", message),
+            #[cfg(feature = "std")]
+            Error::Io(message) => write!(formatter, "i/o error: {}", message),
+            #[cfg(feature = "render")]
+            Error::Window(message) => write!(formatter, "window error: {}", message),
+            #[cfg(feature = "render")]
+            Error::Renderer(message) => write!(formatter, "renderer error: {}", message),
+            #[cfg(feature = "render")]
+            Error::Shader(message) => write!(formatter, "shader error: {}", message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseMatrixError> for Error {
+    fn from(error: ParseMatrixError) -> Error {
+        Error::Parse(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Error {
+        Error::Io(error.to_string())
+    }
+}
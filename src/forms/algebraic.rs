@@ -0,0 +1,196 @@
+use crate::{
+    forms::{implicit::*, parametric::*, polynomial::Polynomial},
+    math::{
+        affine::primitives::{Point, Vector},
+        matrix::Matrix,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// Surfaces of degree three and four, given by their polynomials which are built once
+// on construction
+
+macro_rules! impl_forms_from_polynomial {
+    ($type:ident) => {
+        impl $type {
+            pub fn polynomial(&self) -> &Polynomial {
+                &self.polynomial
+            }
+        }
+
+        impl ImplicitForm for $type {
+            fn implicit_form_value(&self, u: Point) -> f64 {
+                self.polynomial.implicit_form_value(u)
+            }
+        }
+
+        impl DifferentialForm for $type {
+            fn derivative_at(&self, u: Point) -> Vector {
+                self.polynomial.derivative_at(u)
+            }
+        }
+
+        impl SecondDifferentialForm for $type {
+            fn hessian_at(&self, u: Point) -> Matrix<f64, 3, 3> {
+                self.polynomial.hessian_at(u)
+            }
+        }
+    };
+}
+
+fn coordinates() -> [Polynomial; 3] {
+    core::array::from_fn(Polynomial::variable)
+}
+
+// Torus around the z axis, (x² + y² + z² + R² - r²)² - 4R²(x² + y²) = 0
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Torus {
+    major_radius: f64,
+    minor_radius: f64,
+    polynomial: Polynomial,
+}
+
+impl Torus {
+    pub fn new(major_radius: f64, minor_radius: f64) -> Torus {
+        let [x, y, z] = coordinates();
+        let radial = x.powi(2) + y.powi(2);
+        let offset = major_radius.powi(2) - minor_radius.powi(2);
+
+        Torus {
+            major_radius,
+            minor_radius,
+            polynomial: (radial.clone() + z.powi(2) + Polynomial::constant(offset)).powi(2)
+                - radial * (4.0 * major_radius.powi(2)),
+        }
+    }
+
+    pub fn major_radius(&self) -> f64 {
+        self.major_radius
+    }
+
+    pub fn minor_radius(&self) -> f64 {
+        self.minor_radius
+    }
+}
+
+impl_forms_from_polynomial!(Torus);
+
+// Angle u around the z axis and angle v around the core circle
+impl ParametricForm for Torus {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain {
+            periodic_v: true,
+            ..ParameterDomain::around_z([-core::f64::consts::PI, core::f64::consts::PI])
+        }
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let ring = self.major_radius + self.minor_radius * v.cos();
+        Point::new(ring * u.cos(), ring * u.sin(), self.minor_radius * v.sin())
+    }
+
+    fn partial_u(&self, u: f64, v: f64) -> Vector {
+        let ring = self.major_radius + self.minor_radius * v.cos();
+        Vector::new(-ring * u.sin(), ring * u.cos(), 0.0)
+    }
+
+    fn partial_v(&self, u: f64, v: f64) -> Vector {
+        let r = self.minor_radius;
+        Vector::new(-r * v.sin() * u.cos(), -r * v.sin() * u.sin(), r * v.cos())
+    }
+}
+
+// Steiner's Roman surface x²y² + y²z² + z²x² - r xyz = 0, which fits in the cube of
+// half width r / 2 and has three lines of self intersection along the axes
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SteinerSurface {
+    radius: f64,
+    polynomial: Polynomial,
+}
+
+impl SteinerSurface {
+    pub fn new(radius: f64) -> SteinerSurface {
+        let [x, y, z] = coordinates();
+        let (xx, yy, zz) = (x.powi(2), y.powi(2), z.powi(2));
+
+        SteinerSurface {
+            radius,
+            polynomial: &xx * &yy + &yy * &zz + &zz * &xx - x * y * z * radius,
+        }
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+}
+
+impl_forms_from_polynomial!(SteinerSurface);
+
+// Kummer quartic (x² + y² + z² - μ²)² - λ p q r s = 0 with λ = (3μ² - 1) / (3 - μ²)
+// and p, q, r, s the planes of a tetrahedron, which has sixteen real nodes for
+// 1 < μ² < 3
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KummerSurface {
+    mu: f64,
+    polynomial: Polynomial,
+}
+
+impl KummerSurface {
+    pub fn new(mu: f64) -> KummerSurface {
+        let [x, y, z] = coordinates();
+        let one = Polynomial::constant(1.0);
+        let mu_squared = mu * mu;
+        let lambda = (3.0 * mu_squared - 1.0) / (3.0 - mu_squared);
+
+        let sqrt2 = core::f64::consts::SQRT_2;
+        let p = one.clone() - z.clone() - x.clone() * sqrt2;
+        let q = one.clone() - z.clone() + x.clone() * sqrt2;
+        let r = one.clone() + z.clone() + y.clone() * sqrt2;
+        let s = one + z.clone() - y.clone() * sqrt2;
+        let sphere = x.powi(2) + y.powi(2) + z.powi(2) - Polynomial::constant(mu_squared);
+
+        KummerSurface {
+            mu,
+            polynomial: sphere.powi(2) - p * q * r * s * lambda,
+        }
+    }
+
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl_forms_from_polynomial!(KummerSurface);
+
+// Clebsch diagonal cubic x₀³ + x₁³ + x₂³ + x₃³ + x₄³ = 0 on the hyperplane where the
+// coordinates sum to zero, in the chart (x, y, z, 1, -(x + y + z + 1)). All of its
+// 27 lines are real.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClebschCubic {
+    polynomial: Polynomial,
+}
+
+impl ClebschCubic {
+    pub fn new() -> ClebschCubic {
+        let [x, y, z] = coordinates();
+        let one = Polynomial::constant(1.0);
+        let sum = x.clone() + y.clone() + z.clone() + one.clone();
+
+        ClebschCubic {
+            polynomial: x.powi(3) + y.powi(3) + z.powi(3) + one - sum.powi(3),
+        }
+    }
+}
+
+impl Default for ClebschCubic {
+    fn default() -> ClebschCubic {
+        Self::new()
+    }
+}
+
+impl_forms_from_polynomial!(ClebschCubic);
@@ -0,0 +1,459 @@
+use crate::{
+    forms::quadric::{
+        balanced, classify_quadric, homogeneous_scale, inertia, symmetrized, GeneralQuadric,
+        QuadricType,
+    },
+    math::{
+        affine::{
+            primitives::{Point, Vector},
+            transforms::*,
+        },
+        matrix::Matrix,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanonicalQuadric {
+    kind: QuadricType,
+    quadric: GeneralQuadric,
+    transform: AffineTransform,
+    center: Point,
+    axes: [Vector; 3],
+    semi_axes: [Option<f64>; 3],
+}
+
+impl CanonicalQuadric {
+    pub fn kind(&self) -> QuadricType {
+        self.kind
+    }
+
+    // The quadric in principal axes coordinates
+    pub fn quadric(&self) -> GeneralQuadric {
+        self.quadric
+    }
+
+    // Rigid transform mapping canonical coordinates to world coordinates
+    pub fn transform(&self) -> AffineTransform {
+        self.transform
+    }
+
+    // Center of central quadrics, vertex of paraboloids, closest point to the origin
+    // on the axis or plane of symmetry otherwise
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    pub fn axes(&self) -> [Vector; 3] {
+        self.axes
+    }
+
+    // For central quadrics with a nonzero constant, 1 / √|λ| along each axis with a
+    // nonzero square λ and None along the others. Axes the surface does not cross, as
+    // the imaginary axis of hyperboloids, get the length of their imaginary semi-axis.
+    pub fn semi_axes(&self) -> [Option<f64>; 3] {
+        self.semi_axes
+    }
+}
+
+fn dot<const N: usize>(first: &[f64; N], second: &[f64; N]) -> f64 {
+    first.iter().zip(second).map(|(a, b)| a * b).sum()
+}
+
+fn norm<const N: usize>(vector: &[f64; N]) -> f64 {
+    dot(vector, vector).sqrt()
+}
+
+// By elimination with partial pivoting, only its sign is used
+fn determinant<const N: usize>(mut rows: [[f64; N]; N]) -> f64 {
+    let mut determinant = 1.0;
+
+    for i in 0..N {
+        let pivot = (i..N)
+            .max_by(|&a, &b| rows[a][i].abs().total_cmp(&rows[b][i].abs()))
+            .unwrap_or(i);
+        if rows[pivot][i] == 0.0 {
+            return 0.0;
+        }
+        if pivot != i {
+            rows.swap(i, pivot);
+            determinant = -determinant;
+        }
+
+        determinant *= rows[i][i];
+        let (upper, lower) = rows.split_at_mut(i + 1);
+        for row in lower {
+            let factor = row[i] / upper[i][i];
+            for (value, pivot) in row[i..].iter_mut().zip(&upper[i][i..]) {
+                *value -= factor * pivot;
+            }
+        }
+    }
+
+    determinant
+}
+
+struct PrincipalAxes<const N: usize> {
+    values: [f64; N],
+    vectors: [[f64; N]; N],
+    positive: usize,
+    negative: usize,
+    center: [f64; N],
+    linear: [f64; N],
+    constant: f64,
+}
+
+// For a symmetric matrix of size M = N + 1 with the quadratic block in the first N
+// rows and columns
+fn principal_axes<const N: usize, const M: usize>(
+    symmetric: &Matrix<f64, M, M>,
+    threshold: f64,
+) -> PrincipalAxes<N> {
+    let (values, eigenvectors) = symmetric.submatrix::<N, N>(0, 0).symmetric_eigen();
+    let vectors: [[f64; N]; N] =
+        core::array::from_fn(|i| core::array::from_fn(|row| eigenvectors.at(row, i)));
+    let (positive, negative) = inertia(&values, threshold);
+    let b: [f64; N] = core::array::from_fn(|row| symmetric.at(row, N));
+
+    // Solve the quadratic block on its range, what is left of the linear term lies
+    // in its null space
+    let mut center = [0.0; N];
+    let mut linear = [0.0; N];
+    for (&value, vector) in values.iter().zip(vectors.iter()) {
+        let projection = dot(vector, &b);
+        for row in 0..N {
+            if value.abs() > threshold {
+                center[row] -= vector[row] * (projection / value);
+            } else {
+                linear[row] += vector[row] * projection;
+            }
+        }
+    }
+
+    let homogeneous =
+        Matrix::from_data([core::array::from_fn(
+            |i| {
+                if i < N {
+                    center[i]
+                } else {
+                    1.0
+                }
+            },
+        )]);
+    let constant = (homogeneous * *symmetric * homogeneous.transpose()).num();
+
+    PrincipalAxes {
+        values,
+        vectors,
+        positive,
+        negative,
+        center,
+        linear,
+        constant,
+    }
+}
+
+fn negated<const M: usize>(matrix: &Matrix<f64, M, M>) -> Matrix<f64, M, M> {
+    let mut negated = *matrix;
+
+    for row in 0..M {
+        for col in 0..M {
+            *negated.at_mut(row, col) = -matrix.at(row, col);
+        }
+    }
+
+    negated
+}
+
+// The principal axes reduction of a quadric or a conic, in N dimensions for the
+// symmetric matrix of size M = N + 1
+pub(crate) struct Reduction<const N: usize, const M: usize> {
+    // Right handed, with the direction the parabolic ones open to last
+    pub axes: [[f64; N]; N],
+    pub rank: usize,
+    pub parabolic: bool,
+    pub center: [f64; N],
+    pub canonical: Matrix<f64, M, M>,
+    pub semi_axes: [Option<f64>; N],
+}
+
+impl<const N: usize, const M: usize> Reduction<N, M> {
+    // Rigid transform mapping canonical coordinates to the original ones
+    pub fn transform(&self) -> Matrix<f64, M, M> {
+        let mut transform = Matrix::identity();
+
+        for (col, column) in self.axes.iter().chain([&self.center]).enumerate() {
+            for (row, &value) in column.iter().enumerate() {
+                *transform.at_mut(row, col) = value;
+            }
+        }
+
+        transform
+    }
+}
+
+// Principal axes reduction: the quadratic block is diagonalized by a rotation, the
+// linear term is removed by translating to the center, and a linear term along the
+// null space of the block, as for paraboloids, moves the origin to the vertex instead.
+// The equation is negated when needed so that the positive squares dominate and
+// central ones have a negative constant where they can, and scaled so that the
+// constant term is -1 or 1 for central ones and the linear term is minus the last
+// coordinate for parabolic ones, with positive squares first.
+pub(crate) fn principal_reduction<const N: usize, const M: usize>(
+    symmetric: &Matrix<f64, M, M>,
+    tolerance: f64,
+) -> Reduction<N, M> {
+    // Thresholds as for the balanced matrix, scaled back for the linear and constant
+    // terms so that they follow the scale of the coordinates
+    let homogeneous = homogeneous_scale(symmetric);
+    let (values, _) = balanced(symmetric, homogeneous).symmetric_eigen();
+    let scale = values
+        .iter()
+        .fold(0.0_f64, |max, value| max.max(value.abs()));
+    let threshold = tolerance * scale;
+    let linear_threshold = threshold / homogeneous;
+    let constant_threshold = linear_threshold / homogeneous;
+
+    let mut principal = principal_axes::<N, M>(symmetric, threshold);
+    let parabolic = norm(&principal.linear) > linear_threshold;
+    let tie = principal.positive == principal.negative;
+
+    if principal.negative > principal.positive
+        || (tie && !parabolic && principal.constant > constant_threshold)
+    {
+        principal = principal_axes(&negated(symmetric), threshold);
+    }
+
+    // Eigenvalues are sorted descending, so nonzero ones come positive first
+    let mut order: [usize; N] = core::array::from_fn(|i| i);
+    order.sort_by_key(|&i| principal.values[i].abs() <= threshold);
+    let rank = principal.positive + principal.negative;
+
+    let mut axes = order.map(|i| principal.vectors[i]);
+    let mut center = principal.center;
+    let mut canonical = Matrix::<f64, M, M>::zero();
+    for (i, &index) in order.iter().take(rank).enumerate() {
+        *canonical.at_mut(i, i) = principal.values[index];
+    }
+
+    // The axis flipped to make the axes right handed
+    let mut flipped = 0;
+    let normalization;
+    if parabolic {
+        let length = norm(&principal.linear);
+        let direction = principal.linear.map(|value| -value / length);
+        for row in 0..N {
+            center[row] += direction[row] * (principal.constant / (2.0 * length));
+        }
+
+        // The last axis points to where the parabolic one opens, the remaining null
+        // space directions are completed orthogonally to it from the eigenvectors
+        axes[N - 1] = direction;
+        for axis in rank..N - 1 {
+            let residual = |candidate: &[f64; N]| {
+                let mut residual = *candidate;
+                for fixed in axes[..axis].iter().chain([&direction]) {
+                    let projection = dot(&residual, fixed);
+                    for row in 0..N {
+                        residual[row] -= fixed[row] * projection;
+                    }
+                }
+                residual
+            };
+            let completed =
+                principal
+                    .vectors
+                    .iter()
+                    .map(residual)
+                    .fold([0.0; N], |best, candidate| {
+                        if norm(&candidate) > norm(&best) {
+                            candidate
+                        } else {
+                            best
+                        }
+                    });
+            let completed_norm = norm(&completed);
+            axes[axis] = completed.map(|value| value / completed_norm);
+            flipped = axis;
+        }
+
+        *canonical.at_mut(N - 1, N) = -length;
+        *canonical.at_mut(N, N - 1) = -length;
+        normalization = 2.0 * length;
+    } else if principal.constant.abs() > constant_threshold {
+        *canonical.at_mut(N, N) = principal.constant;
+        normalization = principal.constant.abs();
+    } else if rank > 0 {
+        normalization = principal.values[order[rank - 1]].abs();
+    } else {
+        normalization = 1.0;
+    }
+
+    if determinant(axes) < 0.0 {
+        axes[flipped] = axes[flipped].map(|value| -value);
+    }
+
+    for row in 0..M {
+        for col in 0..M {
+            *canonical.at_mut(row, col) /= normalization;
+        }
+    }
+
+    let semi_axes = core::array::from_fn(|i| {
+        let curvature = canonical.at(i, i).abs();
+        let defined = !parabolic && canonical.at(N, N) != 0.0 && curvature > 0.0;
+        defined.then(|| 1.0 / curvature.sqrt())
+    });
+
+    Reduction {
+        axes,
+        rank,
+        parabolic,
+        center,
+        canonical,
+        semi_axes,
+    }
+}
+
+// The canonical equation is in the same layout as the canonical forms of this module,
+// with the axis of paraboloids last
+pub fn reduce_quadric(matrix: &AffineTransform, tolerance: f64) -> CanonicalQuadric {
+    let reduction = principal_reduction::<3, 4>(&symmetrized(matrix), tolerance);
+    let [x, y, z] = reduction.center;
+
+    CanonicalQuadric {
+        kind: classify_quadric(matrix, tolerance),
+        quadric: GeneralQuadric::from_matrix(reduction.canonical),
+        transform: reduction.transform(),
+        center: Point::new(x, y, z),
+        axes: reduction.axes.map(|[x, y, z]| Vector::new(x, y, z)),
+        semi_axes: reduction.semi_axes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::{
+        cylinder::ParabolicCylinder,
+        ellipsoid::Ellipsoid,
+        hyperboloid::{HyperboloidOfOneSheet, HyperboloidOfTwoSheets},
+        implicit::QuadraticForm,
+        paraboloid::EllipticParaboloid,
+        planes::*,
+    };
+
+    fn placement() -> AffineTransform {
+        translate(Vector::new(1.0, -2.0, 0.5)) * rotate_z(0.3) * rotate_x(0.7)
+    }
+
+    // T^-T Q T^-1, the quadric moved by the transform
+    fn placed<F: QuadraticForm>(form: &F) -> AffineTransform {
+        let inverse = placement().try_inverse().unwrap();
+        inverse.transpose() * form.quadratic_form_matrix() * inverse
+    }
+
+    fn assert_right_handed(canonical: &CanonicalQuadric) {
+        let [a, b, c] = canonical.axes();
+        assert!((a * b.cross(c) - 1.0).abs() < 1e-12);
+    }
+
+    // The canonical equation is the original one in canonical coordinates, up to scale
+    fn assert_equivalent(matrix: &AffineTransform, canonical: &CanonicalQuadric) {
+        let transform = canonical.transform();
+        let pulled_back = transform.transpose() * symmetrized(matrix) * transform;
+        let reduced = canonical.quadric().quadratic_form_matrix();
+        let (row, col) = (0..16)
+            .map(|index| (index / 4, index % 4))
+            .max_by(|&(a, b), &(c, d)| reduced.at(a, b).abs().total_cmp(&reduced.at(c, d).abs()))
+            .unwrap();
+        let factor = pulled_back.at(row, col) / reduced.at(row, col);
+
+        for row in 0..4 {
+            for col in 0..4 {
+                assert!((pulled_back.at(row, col) - factor * reduced.at(row, col)).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn ellipsoids_reduce_to_their_center_and_radii() {
+        let ellipsoid = Ellipsoid::new(
+            Point::new(1.0, -2.0, 0.5),
+            [2.0, 3.0, 1.0],
+            rotate_z(0.3) * rotate_x(0.7),
+        );
+        let matrix = ellipsoid.quadratic_form_matrix();
+        let canonical = reduce_quadric(&matrix, 1e-9);
+
+        assert_eq!(canonical.kind(), QuadricType::RealEllipsoid);
+        assert!((canonical.center() - ellipsoid.center()).length() < 1e-12);
+        for (semi_axis, radius) in canonical.semi_axes().iter().zip([1.0, 2.0, 3.0]) {
+            assert!((semi_axis.unwrap() - radius).abs() < 1e-12);
+        }
+        assert_right_handed(&canonical);
+        assert_equivalent(&matrix, &canonical);
+    }
+
+    #[test]
+    fn spheres_of_any_size_keep_their_radius() {
+        for radius in [1e-6, 1e-3, 1e5, 1e8] {
+            let center = Point::new(radius, -2.0 * radius, 0.0);
+            let matrix = Ellipsoid::new(center, [radius; 3], rotate_y(0.2)).quadratic_form_matrix();
+            let canonical = reduce_quadric(&matrix, 1e-9);
+
+            assert_eq!(canonical.kind(), QuadricType::RealEllipsoid);
+            assert!((canonical.center() - center).length() < 1e-12 * radius);
+            for semi_axis in canonical.semi_axes() {
+                assert!((semi_axis.unwrap() / radius - 1.0).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn hyperboloids_give_their_imaginary_semi_axis() {
+        let one_sheet = placed(&HyperboloidOfOneSheet::with_radii(1.0, 2.0, 3.0));
+        let two_sheets = placed(&HyperboloidOfTwoSheets::with_radii(1.0, 2.0, 3.0));
+
+        for matrix in [one_sheet, two_sheets] {
+            let canonical = reduce_quadric(&matrix, 1e-9);
+            for (semi_axis, radius) in canonical.semi_axes().iter().zip([1.0, 2.0, 3.0]) {
+                assert!((semi_axis.unwrap() - radius).abs() < 1e-12);
+            }
+            assert_equivalent(&matrix, &canonical);
+        }
+    }
+
+    #[test]
+    fn paraboloids_open_along_the_last_axis() {
+        let matrix = placed(&EllipticParaboloid::with_curvatures(1.0, 2.0));
+        let canonical = reduce_quadric(&matrix, 1e-9);
+
+        assert_eq!(canonical.kind(), QuadricType::EllipticParaboloid);
+        assert!((canonical.center() - placement() * Point::new(0.0, 0.0, 0.0)).length() < 1e-12);
+        assert!((canonical.axes()[2] - placement() * Vector::new(0.0, 0.0, 1.0)).length() < 1e-12);
+        assert_eq!(canonical.semi_axes(), [None; 3]);
+        assert_right_handed(&canonical);
+        assert_equivalent(&matrix, &canonical);
+    }
+
+    #[test]
+    fn degenerate_quadrics_complete_their_axes() {
+        let matrices = [
+            placed(&ParabolicCylinder::with_curvature(2.0)),
+            placed(&GeneralQuadric::from_coefficients([
+                0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 2.0, -1.0, 1.0,
+            ])),
+            placed(&ParallelPlanes::with_distance(1.5)),
+            placed(&CoincidentPlanes),
+        ];
+
+        for matrix in matrices {
+            let canonical = reduce_quadric(&matrix, 1e-9);
+            assert_right_handed(&canonical);
+            assert_equivalent(&matrix, &canonical);
+        }
+    }
+}
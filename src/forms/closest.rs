@@ -0,0 +1,125 @@
+use crate::{
+    forms::implicit::*,
+    math::affine::primitives::{Point, Vector},
+};
+
+const PROJECTION_ITERATIONS: usize = 16;
+
+// Closest point on the surface to a query point, the outward unit normal there and the
+// Euclidean distance to it, negative for query points inside
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Closest {
+    pub point: Point,
+    pub normal: Vector,
+    pub distance: f64,
+}
+
+pub trait ClosestPoint {
+    fn closest_point(&self, u: Point) -> Closest;
+}
+
+// Newton steps along the gradient until the first order distance to the surface is
+// within the tolerance, and one more, which leaves it well below the tolerance so that
+// distances to nearby projections can be compared
+fn onto_surface<F: ImplicitForm + DifferentialForm + ?Sized>(
+    form: &F,
+    mut u: Point,
+    tolerance: f64,
+) -> Option<Point> {
+    for _ in 0..PROJECTION_ITERATIONS {
+        let value = form.implicit_form_value(u);
+        let gradient = form.derivative_at(u);
+        let length = gradient.length();
+        if !(length > 0.0 && length.is_finite()) {
+            return None;
+        }
+
+        let within = value.abs() <= tolerance * length;
+        u = u - gradient * (value / (length * length));
+        if within {
+            return Some(u);
+        }
+    }
+
+    None
+}
+
+// Closest point for forms without an exact query. Starting from the projection of u
+// onto the surface, it slides the point along the tangent plane towards u and projects
+// it back until u lies along the normal. Steps are scaled by 1 / (1 + d κ), with d the
+// distance along the normal and κ the normal curvature estimated from the last step,
+// which is the Newton step on a sphere, and halved when they do not bring the point
+// closer. This finds a point whose normal passes through u near the projection, which
+// is the closest point for points close enough to the surface, but may be a farther one
+// elsewhere, as on the axes of an ellipsoid. It gives None when the projection or the
+// iteration does not converge.
+pub fn project_closest_point<F: ImplicitForm + DifferentialForm + ?Sized>(
+    form: &F,
+    u: Point,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Option<Closest> {
+    let tangent_offset = |point: Point| {
+        let normal = form.derivative_at(point).normalized();
+        let offset = u - point;
+        (normal, offset - normal * (offset * normal))
+    };
+
+    // Distances change only by rounding close to the closest point, where steps have
+    // to bring u closer to the normal instead
+    let closer = |candidate: Point, next_tangential: Vector, point: Point, tangential: Vector| {
+        let (next, current) = ((u - candidate).length(), (u - point).length());
+        next < current
+            || (next <= current * (1.0 + 4.0 * f64::EPSILON)
+                && next_tangential.length() < tangential.length())
+    };
+
+    let mut point = onto_surface(form, u, tolerance)?;
+    let (mut normal, mut tangential) = tangent_offset(point);
+    let mut curvature = 0.0;
+    let mut fraction = 1.0;
+
+    for _ in 0..max_iterations {
+        if tangential.length() <= tolerance {
+            let distance = (u - point).length();
+            return Some(Closest {
+                point,
+                normal,
+                distance: if form.implicit_form_value(u) < 0.0 {
+                    -distance
+                } else {
+                    distance
+                },
+            });
+        }
+
+        // Past the center of curvature the closest point is unstable, so the step is
+        // only bounded there
+        let denominator = 1.0 + ((u - point) * normal) * curvature;
+        let scale = if denominator > 0.25 {
+            1.0 / denominator
+        } else {
+            4.0
+        };
+
+        let candidate = onto_surface(form, point + tangential * (scale * fraction), tolerance);
+        match candidate.map(|candidate| (candidate, tangent_offset(candidate))) {
+            Some((candidate, (next_normal, next_tangential)))
+                if closer(candidate, next_tangential, point, tangential) =>
+            {
+                let moved = candidate - point;
+                let length = moved * moved;
+                if length > 0.0 {
+                    curvature = (next_normal - normal) * moved / length;
+                }
+
+                (point, normal, tangential) = (candidate, next_normal, next_tangential);
+                fraction = 1.0;
+            }
+            _ => fraction *= 0.5,
+        }
+    }
+
+    None
+}
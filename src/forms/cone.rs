@@ -0,0 +1,55 @@
+use crate::{
+    forms::{implicit::*, parametric::*},
+    math::affine::{
+        primitives::{Point, Vector},
+        transforms::*,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EllipticCone {
+    a: f64,
+    b: f64,
+}
+
+impl EllipticCone {
+    pub fn with_curvatures(a: f64, b: f64) -> EllipticCone {
+        EllipticCone { a, b }
+    }
+
+    pub fn with_radii(rx: f64, ry: f64) -> EllipticCone {
+        Self::with_curvatures(1.0 / (rx * rx), 1.0 / (ry * ry))
+    }
+}
+
+impl QuadraticForm for EllipticCone {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        AffineTransform::diagonal(&[self.a, self.b, -1.0, 0.0])
+    }
+}
+
+// Angle u around the z axis and height v, covering both nappes through the apex where
+// the parametrization is singular
+impl ParametricForm for EllipticCone {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::around_z([f64::NEG_INFINITY, f64::INFINITY])
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Point::new(rx * v * u.cos(), ry * v * u.sin(), v)
+    }
+
+    fn partial_u(&self, u: f64, v: f64) -> Vector {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Vector::new(-rx * v * u.sin(), ry * v * u.cos(), 0.0)
+    }
+
+    fn partial_v(&self, u: f64, _v: f64) -> Vector {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Vector::new(rx * u.cos(), ry * u.sin(), 1.0)
+    }
+}
@@ -0,0 +1,362 @@
+use crate::{
+    error::{Error, Result},
+    forms::implicit::*,
+    math::{
+        affine::primitives::{Point, Vector},
+        matrix::Matrix,
+    },
+};
+
+// Combinators treat the points with a negative value as the solid, so for example a
+// hollowed ellipsoid is Difference::new(outer, inner)
+
+fn scaled(matrix: Matrix<f64, 3, 3>, factor: f64) -> Matrix<f64, 3, 3> {
+    let mut scaled = matrix;
+
+    for row in 0..3 {
+        for col in 0..3 {
+            *scaled.at_mut(row, col) *= factor;
+        }
+    }
+
+    scaled
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Complement<F> {
+    form: F,
+}
+
+impl<F> Complement<F> {
+    pub fn new(form: F) -> Complement<F> {
+        Complement { form }
+    }
+
+    pub fn form(&self) -> &F {
+        &self.form
+    }
+}
+
+impl<F: ImplicitForm> ImplicitForm for Complement<F> {
+    fn implicit_form_value(&self, u: Point) -> f64 {
+        -self.form.implicit_form_value(u)
+    }
+}
+
+impl<F: DifferentialForm> DifferentialForm for Complement<F> {
+    fn derivative_at(&self, u: Point) -> Vector {
+        -self.form.derivative_at(u)
+    }
+}
+
+impl<F: SecondDifferentialForm> SecondDifferentialForm for Complement<F> {
+    fn hessian_at(&self, u: Point) -> Matrix<f64, 3, 3> {
+        scaled(self.form.hessian_at(u), -1.0)
+    }
+}
+
+// The value of the first operand against the second one with its sign, the result
+// and its derivatives are those of the operand selected at the point
+macro_rules! impl_sharp_combinator {
+    ($type:ident, $second_sign:expr, $select_first:expr) => {
+        #[derive(Clone, Copy, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $type<A, B> {
+            first: A,
+            second: B,
+        }
+
+        impl<A, B> $type<A, B> {
+            const SECOND_SIGN: f64 = $second_sign;
+
+            pub fn new(first: A, second: B) -> $type<A, B> {
+                $type { first, second }
+            }
+
+            pub fn first(&self) -> &A {
+                &self.first
+            }
+
+            pub fn second(&self) -> &B {
+                &self.second
+            }
+        }
+
+        impl<A: ImplicitForm, B: ImplicitForm> $type<A, B> {
+            fn first_selected(&self, u: Point) -> bool {
+                let select_first: fn(f64, f64) -> bool = $select_first;
+                select_first(
+                    self.first.implicit_form_value(u),
+                    Self::SECOND_SIGN * self.second.implicit_form_value(u),
+                )
+            }
+        }
+
+        impl<A: ImplicitForm, B: ImplicitForm> ImplicitForm for $type<A, B> {
+            fn implicit_form_value(&self, u: Point) -> f64 {
+                if self.first_selected(u) {
+                    self.first.implicit_form_value(u)
+                } else {
+                    Self::SECOND_SIGN * self.second.implicit_form_value(u)
+                }
+            }
+        }
+
+        impl<A, B> DifferentialForm for $type<A, B>
+        where
+            A: ImplicitForm + DifferentialForm,
+            B: ImplicitForm + DifferentialForm,
+        {
+            fn derivative_at(&self, u: Point) -> Vector {
+                if self.first_selected(u) {
+                    self.first.derivative_at(u)
+                } else {
+                    self.second.derivative_at(u) * Self::SECOND_SIGN
+                }
+            }
+        }
+
+        impl<A, B> SecondDifferentialForm for $type<A, B>
+        where
+            A: ImplicitForm + SecondDifferentialForm,
+            B: ImplicitForm + SecondDifferentialForm,
+        {
+            fn hessian_at(&self, u: Point) -> Matrix<f64, 3, 3> {
+                if self.first_selected(u) {
+                    self.first.hessian_at(u)
+                } else {
+                    scaled(self.second.hessian_at(u), Self::SECOND_SIGN)
+                }
+            }
+        }
+    };
+}
+
+impl_sharp_combinator!(Union, 1.0, |first, second| first <= second);
+impl_sharp_combinator!(Intersection, 1.0, |first, second| first >= second);
+impl_sharp_combinator!(Difference, -1.0, |first, second| first >= second);
+
+// Polynomial smooth minimum of x and y over a band of the given radius, returning the
+// value and the weight of x
+fn smooth_min(x: f64, y: f64, radius: f64) -> (f64, f64) {
+    let weight = (0.5 + 0.5 * (y - x) / radius).clamp(0.0, 1.0);
+    let value = weight * x + (1.0 - weight) * y - radius * weight * (1.0 - weight);
+
+    (value, weight)
+}
+
+// Smooth blends are outer * smin(first_sign * first, second_sign * second). The
+// derivative of the blend weight cancels in the gradient, which is the weighted mix
+// of the operand gradients, and leaves a rank one term in the Hessian inside the band.
+macro_rules! impl_smooth_combinator {
+    ($type:ident, $outer:expr, $first_sign:expr, $second_sign:expr) => {
+        #[derive(Clone, Copy, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $type<A, B> {
+            first: A,
+            second: B,
+            radius: f64,
+        }
+
+        impl<A, B> $type<A, B> {
+            const OUTER: f64 = $outer;
+            const FIRST_SIGN: f64 = $first_sign;
+            const SECOND_SIGN: f64 = $second_sign;
+
+            // Without a positive band there is nothing to blend, so the sharp
+            // combinators are the ones to use
+            pub fn new(first: A, second: B, radius: f64) -> Result<$type<A, B>> {
+                if !(radius > 0.0 && radius.is_finite()) {
+                    return Err(Error::NotPositive { value: radius });
+                }
+
+                Ok($type {
+                    first,
+                    second,
+                    radius,
+                })
+            }
+
+            pub fn radius(&self) -> f64 {
+                self.radius
+            }
+        }
+
+        impl<A: ImplicitForm, B: ImplicitForm> $type<A, B> {
+            fn blend(&self, u: Point) -> (f64, f64) {
+                smooth_min(
+                    Self::FIRST_SIGN * self.first.implicit_form_value(u),
+                    Self::SECOND_SIGN * self.second.implicit_form_value(u),
+                    self.radius,
+                )
+            }
+        }
+
+        impl<A: ImplicitForm, B: ImplicitForm> ImplicitForm for $type<A, B> {
+            fn implicit_form_value(&self, u: Point) -> f64 {
+                Self::OUTER * self.blend(u).0
+            }
+        }
+
+        impl<A, B> DifferentialForm for $type<A, B>
+        where
+            A: ImplicitForm + DifferentialForm,
+            B: ImplicitForm + DifferentialForm,
+        {
+            fn derivative_at(&self, u: Point) -> Vector {
+                let (_, weight) = self.blend(u);
+                let first = self.first.derivative_at(u) * (Self::FIRST_SIGN * weight);
+                let second = self.second.derivative_at(u) * (Self::SECOND_SIGN * (1.0 - weight));
+
+                (first + second) * Self::OUTER
+            }
+        }
+
+        impl<A, B> SecondDifferentialForm for $type<A, B>
+        where
+            A: ImplicitForm + DifferentialForm + SecondDifferentialForm,
+            B: ImplicitForm + DifferentialForm + SecondDifferentialForm,
+        {
+            fn hessian_at(&self, u: Point) -> Matrix<f64, 3, 3> {
+                let (_, weight) = self.blend(u);
+                let mut hessian = scaled(self.first.hessian_at(u), Self::FIRST_SIGN * weight)
+                    + scaled(
+                        self.second.hessian_at(u),
+                        Self::SECOND_SIGN * (1.0 - weight),
+                    );
+
+                if weight > 0.0 && weight < 1.0 {
+                    let difference = self.first.derivative_at(u) * Self::FIRST_SIGN
+                        - self.second.derivative_at(u) * Self::SECOND_SIGN;
+
+                    for row in 0..3 {
+                        for col in 0..3 {
+                            *hessian.at_mut(row, col) -=
+                                difference.at(row) * difference.at(col) / (2.0 * self.radius);
+                        }
+                    }
+                }
+
+                scaled(hessian, Self::OUTER)
+            }
+        }
+    };
+}
+
+impl_smooth_combinator!(SmoothUnion, 1.0, 1.0, 1.0);
+impl_smooth_combinator!(SmoothIntersection, -1.0, -1.0, -1.0);
+impl_smooth_combinator!(SmoothDifference, -1.0, -1.0, 1.0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        forms::{
+            numeric::{default_gradient_step, numeric_gradient},
+            sphere::Sphere,
+            transformed::Transformed,
+        },
+        math::affine::transforms::translate,
+    };
+
+    // Unit spheres around the origin and around (1, 0, 0)
+    fn spheres() -> (Sphere, Transformed<Sphere>) {
+        let shifted = Transformed::new(
+            Sphere::with_radius(1.0),
+            translate(Vector::new(1.0, 0.0, 0.0)),
+        )
+        .unwrap();
+
+        (Sphere::with_radius(1.0), shifted)
+    }
+
+    // Points in both spheres, only in the first, only in the second and in neither
+    const BOTH: [f64; 3] = [0.5, 0.2, 0.0];
+    const FIRST: [f64; 3] = [-0.5, 0.0, 0.3];
+    const SECOND: [f64; 3] = [1.5, -0.2, 0.0];
+    const NEITHER: [[f64; 3]; 2] = [[0.5, 1.5, 0.0], [3.0, 0.0, 0.0]];
+
+    fn assert_membership<F: ImplicitForm>(form: &F, both: bool, first: bool, second: bool) {
+        let point = |[x, y, z]: [f64; 3]| Point::new(x, y, z);
+
+        assert_eq!(form.contains(point(BOTH)), both);
+        assert_eq!(form.contains(point(FIRST)), first);
+        assert_eq!(form.contains(point(SECOND)), second);
+        for u in NEITHER {
+            assert!(!form.contains(point(u)));
+        }
+    }
+
+    #[test]
+    fn sharp_combinators_select_the_points_of_each_solid() {
+        let (first, second) = spheres();
+
+        assert_membership(&Union::new(first, second), true, true, true);
+        assert_membership(&Intersection::new(first, second), true, false, false);
+        assert_membership(&Difference::new(first, second), false, true, false);
+        assert_membership(&Difference::new(second, first), false, false, true);
+        assert!(Complement::new(first).contains(Point::new(3.0, 0.0, 0.0)));
+        assert!(!Complement::new(first).contains(Point::new(0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn smooth_combinators_keep_the_points_away_from_the_seam() {
+        let (first, second) = spheres();
+
+        for radius in [0.05, 0.2] {
+            let union = SmoothUnion::new(first, second, radius).unwrap();
+            let intersection = SmoothIntersection::new(first, second, radius).unwrap();
+            let difference = SmoothDifference::new(first, second, radius).unwrap();
+
+            assert_membership(&union, true, true, true);
+            assert_membership(&intersection, true, false, false);
+            assert_membership(&difference, false, true, false);
+        }
+    }
+
+    #[test]
+    fn smooth_combinators_stay_within_a_quarter_radius_of_the_sharp_ones() {
+        let (first, second) = spheres();
+        let radius = 0.3;
+        let union = SmoothUnion::new(first, second, radius).unwrap();
+        let intersection = SmoothIntersection::new(first, second, radius).unwrap();
+        let sharp_union = Union::new(first, second);
+        let sharp_intersection = Intersection::new(first, second);
+
+        for x in [-1.5, -0.2, 0.45, 0.5, 0.55, 1.3, 2.5] {
+            let u = Point::new(x, 0.4, 0.0);
+            let (a, b) = (first.implicit_form_value(u), second.implicit_form_value(u));
+
+            let smooth = union.implicit_form_value(u);
+            let sharp = sharp_union.implicit_form_value(u);
+            assert!(smooth <= sharp && sharp - smooth <= 0.25 * radius);
+
+            let smooth = intersection.implicit_form_value(u);
+            let sharp = sharp_intersection.implicit_form_value(u);
+            assert!(smooth >= sharp && smooth - sharp <= 0.25 * radius);
+
+            // Outside the band the blend is exactly the sharp combinator
+            if (a - b).abs() >= radius {
+                assert_eq!(
+                    union.implicit_form_value(u),
+                    sharp_union.implicit_form_value(u)
+                );
+            }
+
+            let numeric = numeric_gradient(&union, u, default_gradient_step());
+            assert!((union.derivative_at(u) - numeric).length() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn smooth_combinators_need_a_positive_radius() {
+        let (first, second) = spheres();
+
+        for radius in [0.0, -0.1, f64::NAN, f64::INFINITY] {
+            assert!(SmoothUnion::new(first, second, radius).is_err());
+            assert!(SmoothIntersection::new(first, second, radius).is_err());
+            assert!(SmoothDifference::new(first, second, radius).is_err());
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{
+    forms::implicit::*,
+    math::{
+        affine::primitives::{Point, Vector},
+        matrix::Matrix,
+    },
+    primitives::color::Color,
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// Curvatures are taken with respect to the outward normal, the gradient direction,
+// so that convex surfaces such as spheres have positive curvatures
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Curvature {
+    pub normal: Vector,
+    pub gaussian: f64,
+    pub mean: f64,
+    // Ordered from largest to smallest, with matching unit directions
+    pub principal: [f64; 2],
+    pub directions: [Vector; 2],
+}
+
+impl Curvature {
+    pub fn is_umbilic(&self, tolerance: f64) -> bool {
+        (self.principal[0] - self.principal[1]).abs() <= tolerance
+    }
+}
+
+// Goldman, Curvature formulas for implicit curves and surfaces, 2005
+pub fn gaussian_curvature<F>(form: &F, u: Point) -> Option<f64>
+where
+    F: DifferentialForm + SecondDifferentialForm + ?Sized,
+{
+    let gradient = form.derivative_at(u);
+    let length = gradient.length();
+    if length == 0.0 {
+        return None;
+    }
+
+    let hessian = form.hessian_at(u);
+    let mut bordered = Matrix::<f64, 4, 4>::zero();
+    for row in 0..3 {
+        for col in 0..3 {
+            *bordered.at_mut(row, col) = hessian.at(row, col);
+        }
+        *bordered.at_mut(row, 3) = gradient.at(row);
+        *bordered.at_mut(3, row) = gradient.at(row);
+    }
+
+    Some(-determinant(&bordered) / length.powi(4))
+}
+
+pub fn mean_curvature<F>(form: &F, u: Point) -> Option<f64>
+where
+    F: DifferentialForm + SecondDifferentialForm + ?Sized,
+{
+    let gradient = form.derivative_at(u);
+    let length = gradient.length();
+    if length == 0.0 {
+        return None;
+    }
+
+    let hessian = form.hessian_at(u);
+    let g = gradient.as_matrix().submatrix::<3, 1>(0, 0);
+    let normal_term = (g.transpose() * hessian * g).num();
+
+    Some((length * length * hessian.trace() - normal_term) / (2.0 * length.powi(3)))
+}
+
+// The principal curvatures are the eigenvalues of the shape operator, the Hessian
+// restricted to the tangent plane and divided by the gradient length. Working in a
+// tangent basis keeps the normal, with its zero eigenvalue, out of the problem.
+pub fn curvature_at<F>(form: &F, u: Point) -> Option<Curvature>
+where
+    F: DifferentialForm + SecondDifferentialForm + ?Sized,
+{
+    let gradient = form.derivative_at(u);
+    let length = gradient.length();
+    if length == 0.0 {
+        return None;
+    }
+
+    let normal = gradient * (1.0 / length);
+    let [t1, t2] = tangent_basis(normal);
+
+    let hessian = form.hessian_at(u);
+    let form_on = |a: Vector, b: Vector| {
+        let mut sum = 0.0;
+        for row in 0..3 {
+            for col in 0..3 {
+                sum += a.at(row) * hessian.at(row, col) * b.at(col);
+            }
+        }
+        sum / length
+    };
+    let (a, b, d) = (form_on(t1, t1), form_on(t1, t2), form_on(t2, t2));
+
+    // Closed form of the symmetric 2x2 eigenproblem, the largest eigenvalue has the
+    // eigenvector at half the angle of (a - d, 2b)
+    let mean = 0.5 * (a + d);
+    let radius = (0.5 * (a - d)).hypot(b);
+    let angle = 0.5 * (2.0 * b).atan2(a - d);
+    let (sin, cos) = angle.sin_cos();
+    let first = t1 * cos + t2 * sin;
+    let second = t2 * cos - t1 * sin;
+
+    let principal = [mean + radius, mean - radius];
+
+    Some(Curvature {
+        normal,
+        gaussian: a * d - b * b,
+        mean,
+        principal,
+        directions: [first, second],
+    })
+}
+
+// Orthonormal tangents with t1 × t2 along the normal, starting from the axis least
+// aligned with it
+fn tangent_basis(normal: Vector) -> [Vector; 2] {
+    let axis = (0..3)
+        .min_by(|&a, &b| normal.at(a).abs().total_cmp(&normal.at(b).abs()))
+        .unwrap_or(0);
+    let mut coordinates = [0.0; 3];
+    coordinates[axis] = 1.0;
+    let axis = Vector::new(coordinates[0], coordinates[1], coordinates[2]);
+
+    let t1 = normal.cross(axis).normalized();
+    [t1, normal.cross(t1)]
+}
+
+fn determinant(matrix: &Matrix<f64, 4, 4>) -> f64 {
+    let m = |row: usize, col: usize| matrix.at(row, col);
+    let minor = |c0: usize, c1: usize, c2: usize| {
+        m(1, c0) * (m(2, c1) * m(3, c2) - m(2, c2) * m(3, c1))
+            - m(1, c1) * (m(2, c0) * m(3, c2) - m(2, c2) * m(3, c0))
+            + m(1, c2) * (m(2, c0) * m(3, c1) - m(2, c1) * m(3, c0))
+    };
+
+    m(0, 0) * minor(1, 2, 3) - m(0, 1) * minor(0, 2, 3) + m(0, 2) * minor(0, 1, 3)
+        - m(0, 3) * minor(0, 1, 2)
+}
+
+// Diverging colour map from blue for negative through white to red for positive
+// curvature, saturating at the given range
+pub fn curvature_color(curvature: f64, range: f64) -> Color {
+    let t = (curvature / range).clamp(-1.0, 1.0) as f32;
+
+    if t >= 0.0 {
+        Color {
+            r: 1.0,
+            g: 1.0 - t,
+            b: 1.0 - t,
+            a: 1.0,
+        }
+    } else {
+        Color {
+            r: 1.0 + t,
+            g: 1.0 + t,
+            b: 1.0,
+            a: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::{cylinder::EllipticCylinder, ellipsoid::Ellipsoid, sphere::Sphere};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-12, "{actual} != {expected}");
+    }
+
+    // Directions are only defined up to sign
+    fn assert_along(direction: Vector, expected: Vector) {
+        assert_close((direction * expected).abs(), 1.0);
+    }
+
+    #[test]
+    fn spheres_curve_by_their_inverse_radius() {
+        let sphere = Sphere::with_radius(2.5);
+        let points = [
+            Point::new(2.5, 0.0, 0.0),
+            Point::new(0.0, -2.5, 0.0),
+            Point::new(1.5, 2.0, 0.0),
+            Point::new(-1.2, 1.6, 1.5),
+        ];
+
+        for u in points {
+            let curvature = curvature_at(&sphere, u).unwrap();
+            for principal in curvature.principal {
+                assert_close(principal, 0.4);
+            }
+            assert_close(curvature.gaussian, 0.16);
+            assert_close(curvature.mean, 0.4);
+            assert!(curvature.is_umbilic(1e-12));
+            assert_close(
+                curvature.directions[0].cross(curvature.directions[1]) * curvature.normal,
+                1.0,
+            );
+        }
+    }
+
+    #[test]
+    fn cylinders_curve_only_around_their_axis() {
+        let cylinder = EllipticCylinder::with_radii(2.0, 2.0);
+
+        for (u, around) in [
+            (Point::new(2.0, 0.0, 1.0), Vector::new(0.0, 1.0, 0.0)),
+            (Point::new(0.0, -2.0, -3.0), Vector::new(1.0, 0.0, 0.0)),
+        ] {
+            let curvature = curvature_at(&cylinder, u).unwrap();
+            assert_close(curvature.principal[0], 0.5);
+            assert_close(curvature.principal[1], 0.0);
+            assert_along(curvature.directions[0], around);
+            assert_along(curvature.directions[1], Vector::new(0.0, 0.0, 1.0));
+            assert_close(curvature.gaussian, 0.0);
+            assert_close(curvature.mean, 0.25);
+        }
+    }
+
+    #[test]
+    fn ellipsoid_vertices_curve_by_radius_over_squared_radius() {
+        let ellipsoid = Ellipsoid::with_radii(1.0, 2.0, 3.0);
+        let [x, y, z] = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, 0.0, 1.0),
+        ];
+
+        for (u, principal, directions) in [
+            (Point::new(1.0, 0.0, 0.0), [1.0 / 4.0, 1.0 / 9.0], [y, z]),
+            (Point::new(0.0, -2.0, 0.0), [2.0, 2.0 / 9.0], [x, z]),
+            (Point::new(0.0, 0.0, 3.0), [3.0, 3.0 / 4.0], [x, y]),
+        ] {
+            let curvature = curvature_at(&ellipsoid, u).unwrap();
+            for i in 0..2 {
+                assert_close(curvature.principal[i], principal[i]);
+                assert_along(curvature.directions[i], directions[i]);
+            }
+            assert_close(curvature.gaussian, principal[0] * principal[1]);
+            assert_close(
+                curvature.gaussian,
+                gaussian_curvature(&ellipsoid, u).unwrap(),
+            );
+            assert_close(curvature.mean, mean_curvature(&ellipsoid, u).unwrap());
+        }
+    }
+}
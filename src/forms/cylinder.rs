@@ -0,0 +1,150 @@
+use crate::{
+    forms::{implicit::*, parametric::*},
+    math::affine::{
+        primitives::{Point, Vector},
+        transforms::*,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EllipticCylinder {
+    a: f64,
+    b: f64,
+}
+
+impl EllipticCylinder {
+    pub fn with_curvatures(a: f64, b: f64) -> EllipticCylinder {
+        EllipticCylinder { a, b }
+    }
+
+    pub fn with_radii(rx: f64, ry: f64) -> EllipticCylinder {
+        Self::with_curvatures(1.0 / (rx * rx), 1.0 / (ry * ry))
+    }
+}
+
+impl QuadraticForm for EllipticCylinder {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        AffineTransform::diagonal(&[self.a, self.b, 0.0, -1.0])
+    }
+}
+
+// Angle u around the z axis and height v
+impl ParametricForm for EllipticCylinder {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::around_z([f64::NEG_INFINITY, f64::INFINITY])
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Point::new(rx * u.cos(), ry * u.sin(), v)
+    }
+
+    fn partial_u(&self, u: f64, _v: f64) -> Vector {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Vector::new(-rx * u.sin(), ry * u.cos(), 0.0)
+    }
+
+    fn partial_v(&self, _u: f64, _v: f64) -> Vector {
+        Vector::new(0.0, 0.0, 1.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HyperbolicCylinder {
+    a: f64,
+    b: f64,
+}
+
+impl HyperbolicCylinder {
+    pub fn with_curvatures(a: f64, b: f64) -> HyperbolicCylinder {
+        HyperbolicCylinder { a, b }
+    }
+
+    pub fn with_radii(rx: f64, ry: f64) -> HyperbolicCylinder {
+        Self::with_curvatures(1.0 / (rx * rx), 1.0 / (ry * ry))
+    }
+}
+
+impl QuadraticForm for HyperbolicCylinder {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        AffineTransform::diagonal(&[self.a, -self.b, 0.0, -1.0])
+    }
+}
+
+// The branch with positive x, by the hyperbolic angle u and height v
+impl ParametricForm for HyperbolicCylinder {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::new(
+            [f64::NEG_INFINITY, f64::INFINITY],
+            [f64::NEG_INFINITY, f64::INFINITY],
+        )
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Point::new(rx * u.cosh(), ry * u.sinh(), v)
+    }
+
+    fn partial_u(&self, u: f64, _v: f64) -> Vector {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Vector::new(rx * u.sinh(), ry * u.cosh(), 0.0)
+    }
+
+    fn partial_v(&self, _u: f64, _v: f64) -> Vector {
+        Vector::new(0.0, 0.0, 1.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParabolicCylinder {
+    a: f64,
+}
+
+impl ParabolicCylinder {
+    pub fn with_curvature(a: f64) -> ParabolicCylinder {
+        ParabolicCylinder { a }
+    }
+
+    pub fn with_radius(rx: f64) -> ParabolicCylinder {
+        Self::with_curvature(1.0 / (rx * rx))
+    }
+}
+
+impl QuadraticForm for ParabolicCylinder {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        let mut matrix = AffineTransform::diagonal(&[self.a, 0.0, 0.0, 0.0]);
+
+        *matrix.at_mut(1, 3) = -0.5;
+        *matrix.at_mut(3, 1) = -0.5;
+
+        matrix
+    }
+}
+
+// The graph y = a x² over u = x and height v, oriented along -y where the form
+// increases
+impl ParametricForm for ParabolicCylinder {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::new(
+            [f64::NEG_INFINITY, f64::INFINITY],
+            [f64::NEG_INFINITY, f64::INFINITY],
+        )
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        Point::new(u, self.a * u * u, v)
+    }
+
+    fn partial_u(&self, u: f64, _v: f64) -> Vector {
+        Vector::new(1.0, 2.0 * self.a * u, 0.0)
+    }
+
+    fn partial_v(&self, _u: f64, _v: f64) -> Vector {
+        Vector::new(0.0, 0.0, 1.0)
+    }
+}
@@ -0,0 +1,491 @@
+use crate::{
+    error::{Error, Result},
+    forms::{csg::*, implicit::*, ray::Ray},
+    math::affine::primitives::{Point, Vector},
+};
+
+// Signed distance to the surface, negative inside. Forms that cannot give the exact
+// distance return a bound, a value with the right sign and a magnitude no larger than
+// the distance, which is what sphere tracing and offsets rely on.
+pub trait DistanceForm {
+    fn signed_distance(&self, u: Point) -> f64;
+}
+
+fn direction_or_default(vector: Vector) -> Vector {
+    let length = vector.length();
+    if length > 0.0 {
+        vector * (1.0 / length)
+    } else {
+        Vector::new(0.0, 0.0, 1.0)
+    }
+}
+
+// Distance forms are their own implicit forms, with gradients of unit length almost
+// everywhere
+macro_rules! impl_implicit_from_distance {
+    ($type:ident) => {
+        impl ImplicitForm for $type {
+            fn implicit_form_value(&self, u: Point) -> f64 {
+                self.signed_distance(u)
+            }
+        }
+    };
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SphereSdf {
+    center: Point,
+    radius: f64,
+}
+
+impl SphereSdf {
+    pub fn new(center: Point, radius: f64) -> SphereSdf {
+        SphereSdf { center, radius }
+    }
+}
+
+impl DistanceForm for SphereSdf {
+    fn signed_distance(&self, u: Point) -> f64 {
+        (u - self.center).length() - self.radius
+    }
+}
+
+impl DifferentialForm for SphereSdf {
+    fn derivative_at(&self, u: Point) -> Vector {
+        direction_or_default(u - self.center)
+    }
+}
+
+impl_implicit_from_distance!(SphereSdf);
+
+// Axis aligned box
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoxSdf {
+    center: Point,
+    half_extents: Vector,
+}
+
+impl BoxSdf {
+    pub fn new(center: Point, half_extents: Vector) -> BoxSdf {
+        BoxSdf {
+            center,
+            half_extents,
+        }
+    }
+
+    // Per axis distance outside the slab, negative inside it
+    fn excess(&self, u: Point) -> [f64; 3] {
+        let local = u - self.center;
+        core::array::from_fn(|i| local.at(i).abs() - self.half_extents.at(i))
+    }
+}
+
+impl DistanceForm for BoxSdf {
+    fn signed_distance(&self, u: Point) -> f64 {
+        let q = self.excess(u);
+        let outside = Vector::new(q[0].max(0.0), q[1].max(0.0), q[2].max(0.0)).length();
+        let inside = q[0].max(q[1]).max(q[2]).min(0.0);
+
+        outside + inside
+    }
+}
+
+impl DifferentialForm for BoxSdf {
+    fn derivative_at(&self, u: Point) -> Vector {
+        let local = u - self.center;
+        let q = self.excess(u);
+        let sign = |i: usize| if local.at(i) < 0.0 { -1.0 } else { 1.0 };
+
+        if q.iter().any(|&excess| excess > 0.0) {
+            let outside: [f64; 3] = core::array::from_fn(|i| q[i].max(0.0) * sign(i));
+            direction_or_default(Vector::new(outside[0], outside[1], outside[2]))
+        } else {
+            let axis = (0..3).fold(0, |best, i| if q[i] > q[best] { i } else { best });
+            let mut normal = [0.0; 3];
+            normal[axis] = sign(axis);
+            Vector::new(normal[0], normal[1], normal[2])
+        }
+    }
+}
+
+impl_implicit_from_distance!(BoxSdf);
+
+// Torus around the z axis through the center
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TorusSdf {
+    center: Point,
+    major_radius: f64,
+    minor_radius: f64,
+}
+
+impl TorusSdf {
+    pub fn new(center: Point, major_radius: f64, minor_radius: f64) -> TorusSdf {
+        TorusSdf {
+            center,
+            major_radius,
+            minor_radius,
+        }
+    }
+
+    // Offset from the nearest point of the core circle
+    fn core_offset(&self, u: Point) -> Vector {
+        let local = u - self.center;
+        let radial = Vector::new(local.at(0), local.at(1), 0.0);
+        let length = radial.length();
+        let direction = if length > 0.0 {
+            radial * (1.0 / length)
+        } else {
+            Vector::new(1.0, 0.0, 0.0)
+        };
+
+        local - direction * self.major_radius
+    }
+}
+
+impl DistanceForm for TorusSdf {
+    fn signed_distance(&self, u: Point) -> f64 {
+        self.core_offset(u).length() - self.minor_radius
+    }
+}
+
+impl DifferentialForm for TorusSdf {
+    fn derivative_at(&self, u: Point) -> Vector {
+        direction_or_default(self.core_offset(u))
+    }
+}
+
+impl_implicit_from_distance!(TorusSdf);
+
+// Points within the radius of the segment between the two end points
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CapsuleSdf {
+    start: Point,
+    end: Point,
+    radius: f64,
+}
+
+impl CapsuleSdf {
+    pub fn new(start: Point, end: Point, radius: f64) -> CapsuleSdf {
+        CapsuleSdf { start, end, radius }
+    }
+
+    fn segment_offset(&self, u: Point) -> Vector {
+        let axis = self.end - self.start;
+        let length_squared = axis * axis;
+        let t = if length_squared > 0.0 {
+            (((u - self.start) * axis) / length_squared).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        u - (self.start + axis * t)
+    }
+}
+
+impl DistanceForm for CapsuleSdf {
+    fn signed_distance(&self, u: Point) -> f64 {
+        self.segment_offset(u).length() - self.radius
+    }
+}
+
+impl DifferentialForm for CapsuleSdf {
+    fn derivative_at(&self, u: Point) -> Vector {
+        direction_or_default(self.segment_offset(u))
+    }
+}
+
+impl_implicit_from_distance!(CapsuleSdf);
+
+// Surface moved outward by the distance, or inward for negative distances, which is
+// exact for exact distance forms
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Offset<F> {
+    form: F,
+    distance: f64,
+}
+
+impl<F> Offset<F> {
+    pub fn new(form: F, distance: f64) -> Offset<F> {
+        Offset { form, distance }
+    }
+}
+
+impl<F: DistanceForm> DistanceForm for Offset<F> {
+    fn signed_distance(&self, u: Point) -> f64 {
+        self.form.signed_distance(u) - self.distance
+    }
+}
+
+impl<F: DistanceForm> ImplicitForm for Offset<F> {
+    fn implicit_form_value(&self, u: Point) -> f64 {
+        self.signed_distance(u)
+    }
+}
+
+impl<F: DifferentialForm> DifferentialForm for Offset<F> {
+    fn derivative_at(&self, u: Point) -> Vector {
+        self.form.derivative_at(u)
+    }
+}
+
+// Distance bound f / L of an implicit form whose values change by at most L times the
+// distance between points, |f(x) - f(y)| <= L |x - y|. Since f vanishes on the surface
+// this never exceeds the distance. Polynomial forms are only Lipschitz on bounded
+// regions, so the constant has to hold wherever the bound is evaluated.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LipschitzBound<F> {
+    form: F,
+    lipschitz_constant: f64,
+}
+
+impl<F> LipschitzBound<F> {
+    pub fn new(form: F, lipschitz_constant: f64) -> Result<LipschitzBound<F>> {
+        if !(lipschitz_constant > 0.0 && lipschitz_constant.is_finite()) {
+            return Err(Error::NotPositive {
+                value: lipschitz_constant,
+            });
+        }
+
+        Ok(LipschitzBound {
+            form,
+            lipschitz_constant,
+        })
+    }
+
+    pub fn form(&self) -> &F {
+        &self.form
+    }
+
+    pub fn lipschitz_constant(&self) -> f64 {
+        self.lipschitz_constant
+    }
+}
+
+impl<F: ImplicitForm> DistanceForm for LipschitzBound<F> {
+    fn signed_distance(&self, u: Point) -> f64 {
+        self.form.implicit_form_value(u) / self.lipschitz_constant
+    }
+}
+
+impl<F: ImplicitForm> ImplicitForm for LipschitzBound<F> {
+    fn implicit_form_value(&self, u: Point) -> f64 {
+        self.signed_distance(u)
+    }
+}
+
+impl<F: DifferentialForm> DifferentialForm for LipschitzBound<F> {
+    fn derivative_at(&self, u: Point) -> Vector {
+        self.form.derivative_at(u) * (1.0 / self.lipschitz_constant)
+    }
+}
+
+// First order estimate f / |∇f| of the distance, exact for planes and close to the
+// surface of any form whose gradient does not vanish there. Unlike LipschitzBound it
+// needs no constant, but it can overestimate the distance where the gradient shrinks
+// away from the surface, so it is not safe for sphere tracing. At critical points the
+// value itself is returned.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GradientEstimate<F> {
+    form: F,
+}
+
+impl<F> GradientEstimate<F> {
+    pub fn new(form: F) -> GradientEstimate<F> {
+        GradientEstimate { form }
+    }
+
+    pub fn form(&self) -> &F {
+        &self.form
+    }
+}
+
+impl<F: ImplicitForm + DifferentialForm> DistanceForm for GradientEstimate<F> {
+    fn signed_distance(&self, u: Point) -> f64 {
+        let value = self.form.implicit_form_value(u);
+        let gradient = self.form.derivative_at(u).length();
+
+        if gradient > 0.0 {
+            value / gradient
+        } else {
+            value
+        }
+    }
+}
+
+impl<F: ImplicitForm + DifferentialForm> ImplicitForm for GradientEstimate<F> {
+    fn implicit_form_value(&self, u: Point) -> f64 {
+        self.signed_distance(u)
+    }
+}
+
+// The unit normal, which is the gradient of the estimate on the surface
+impl<F: DifferentialForm> DifferentialForm for GradientEstimate<F> {
+    fn derivative_at(&self, u: Point) -> Vector {
+        direction_or_default(self.form.derivative_at(u))
+    }
+}
+
+// Union and intersection keep exact distances outside and inside respectively, and
+// bounds everywhere
+impl<A: DistanceForm, B: DistanceForm> DistanceForm for Union<A, B> {
+    fn signed_distance(&self, u: Point) -> f64 {
+        self.first()
+            .signed_distance(u)
+            .min(self.second().signed_distance(u))
+    }
+}
+
+impl<A: DistanceForm, B: DistanceForm> DistanceForm for Intersection<A, B> {
+    fn signed_distance(&self, u: Point) -> f64 {
+        self.first()
+            .signed_distance(u)
+            .max(self.second().signed_distance(u))
+    }
+}
+
+impl<A: DistanceForm, B: DistanceForm> DistanceForm for Difference<A, B> {
+    fn signed_distance(&self, u: Point) -> f64 {
+        self.first()
+            .signed_distance(u)
+            .max(-self.second().signed_distance(u))
+    }
+}
+
+impl<F: DistanceForm> DistanceForm for Complement<F> {
+    fn signed_distance(&self, u: Point) -> f64 {
+        -self.form().signed_distance(u)
+    }
+}
+
+// Steps along the ray by the distance to the surface until it is within the tolerance,
+// returning the ray parameter of the hit. The ray direction has to be of unit length
+// and the form must never overestimate the distance for the steps to be safe, so a
+// form that only estimates it can step through surfaces.
+pub fn sphere_trace<F: DistanceForm + ?Sized>(
+    form: &F,
+    ray: &Ray,
+    max_distance: f64,
+    tolerance: f64,
+    max_steps: usize,
+) -> Option<f64> {
+    let mut t = 0.0;
+
+    for _ in 0..max_steps {
+        let distance = form.signed_distance(ray.at(t));
+
+        if distance.abs() <= tolerance {
+            return Some(t);
+        }
+
+        t += distance.abs();
+        if t > max_distance {
+            return None;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::{quadric::GeneralQuadric, sphere::Sphere};
+
+    // x² + y² + z² - 1 has a gradient of length 2 |u|, at most 4 within radius 2
+    fn unit_sphere_bound() -> LipschitzBound<Sphere> {
+        LipschitzBound::new(Sphere::with_radius(1.0), 4.0).unwrap()
+    }
+
+    #[test]
+    fn lipschitz_bound_never_exceeds_the_distance() {
+        let bound = unit_sphere_bound();
+
+        for u in sample_points() {
+            let distance = (u - Point::new(0.0, 0.0, 0.0)).length() - 1.0;
+            let estimate = bound.signed_distance(u);
+
+            assert!(estimate.abs() <= distance.abs() + 1e-12);
+            assert!(estimate * distance >= 0.0);
+        }
+    }
+
+    // The plane 3z = 3, whose value is three times the distance
+    fn plane() -> GeneralQuadric {
+        GeneralQuadric::from_coefficients([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 3.0, -3.0])
+    }
+
+    fn sample_points() -> impl Iterator<Item = Point> {
+        (0..=40).flat_map(|i| {
+            let radius = 0.05 * i as f64;
+            [
+                Vector::new(1.0, 0.0, 0.0),
+                Vector::new(0.0, -0.6, 0.8),
+                Vector::new(0.48, 0.6, -0.64),
+            ]
+            .map(|direction| Point::new(0.0, 0.0, 0.0) + direction * radius)
+        })
+    }
+
+    #[test]
+    fn gradient_estimate_is_exact_for_planes() {
+        let estimate = GradientEstimate::new(plane());
+
+        for u in sample_points() {
+            let distance = u.at(2) - 1.0;
+            assert!((estimate.signed_distance(u) - distance).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn gradient_estimate_is_second_order_close_to_spheres() {
+        let estimate = GradientEstimate::new(Sphere::with_radius(1.0));
+
+        for u in sample_points().filter(|u| (*u - Point::new(0.0, 0.0, 0.0)).length() > 0.0) {
+            let radius = (u - Point::new(0.0, 0.0, 0.0)).length();
+            let distance = radius - 1.0;
+            let error = estimate.signed_distance(u) - distance;
+
+            // (r² - 1) / 2r falls short of r - 1 by (r - 1)² / 2r
+            let expected = -distance * distance / (2.0 * radius);
+            assert!((error - expected).abs() < 1e-12 * (1.0 + expected.abs()));
+            assert!(estimate.signed_distance(u) * distance >= 0.0);
+        }
+
+        // The center is a critical point
+        assert_eq!(estimate.signed_distance(Point::new(0.0, 0.0, 0.0)), -1.0);
+    }
+
+    #[test]
+    fn lipschitz_bound_with_the_gradient_length_is_exact_for_planes() {
+        let exact = LipschitzBound::new(plane(), 3.0).unwrap();
+        let loose = LipschitzBound::new(plane(), 5.0).unwrap();
+
+        for u in sample_points() {
+            let distance = u.at(2) - 1.0;
+            assert!((exact.signed_distance(u) - distance).abs() < 1e-12);
+            assert!((loose.signed_distance(u) - 0.6 * distance).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn lipschitz_bound_rejects_non_positive_constants() {
+        for constant in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(LipschitzBound::new(Sphere::with_radius(1.0), constant).is_err());
+        }
+    }
+
+    #[test]
+    fn sphere_tracing_a_bound_stops_at_the_surface() {
+        let ray = Ray::new(Point::new(0.0, 0.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+        let t = sphere_trace(&unit_sphere_bound(), &ray, 10.0, 1e-9, 1000).unwrap();
+
+        assert!(t <= 1.0 && t > 1.0 - 1e-8);
+    }
+}
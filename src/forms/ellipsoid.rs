@@ -1,24 +1,564 @@
-use crate::{forms::implicit::*, math::affine::transforms::*};
+use crate::{
+    error::{Error, Result},
+    forms::{closest::*, distance::DistanceForm, implicit::*, parametric::*},
+    math::{
+        affine::{
+            primitives::{Point, Vector},
+            transforms::*,
+        },
+        matrix::Matrix,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
+// Points x with |R^T (x - center)|² weighted by the curvatures equal to 1, where the
+// columns of the rotation R are the principal axes
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ellipsoid {
-    a: f64,
-    b: f64,
-    c: f64,
+    center: Point,
+    rotation: AffineTransform,
+    curvatures: [f64; 3],
 }
 
 impl Ellipsoid {
     pub fn with_curvatures(a: f64, b: f64, c: f64) -> Ellipsoid {
-        Ellipsoid { a, b, c }
+        Ellipsoid {
+            center: Point::new(0.0, 0.0, 0.0),
+            rotation: AffineTransform::identity(),
+            curvatures: [a, b, c],
+        }
     }
 
     pub fn with_radii(rx: f64, ry: f64, rz: f64) -> Ellipsoid {
         Self::with_curvatures(1.0 / (rx * rx), 1.0 / (ry * ry), 1.0 / (rz * rz))
     }
+
+    // The rotation is expected to be orthonormal without translation
+    pub fn new(center: Point, radii: [f64; 3], rotation: AffineTransform) -> Ellipsoid {
+        Ellipsoid {
+            center,
+            rotation,
+            curvatures: radii.map(|radius| 1.0 / (radius * radius)),
+        }
+    }
+
+    // Ellipsoid of the points center + x u + y v + z w with x² + y² + z² = 1, for any
+    // linearly independent u, v and w
+    pub fn from_conjugate_semi_axes(center: Point, semi_axes: [Vector; 3]) -> Result<Ellipsoid> {
+        let mut shape = Matrix::<f64, 3, 3>::zero();
+
+        for row in 0..3 {
+            for col in 0..3 {
+                *shape.at_mut(row, col) = semi_axes
+                    .iter()
+                    .map(|axis| axis.at(row) * axis.at(col))
+                    .sum();
+            }
+        }
+
+        Self::from_shape_matrix(center, shape)
+    }
+
+    // Ellipsoid of the points x with (x - center)^T S^-1 (x - center) = 1
+    pub fn from_shape_matrix(center: Point, shape: Matrix<f64, 3, 3>) -> Result<Ellipsoid> {
+        let mut symmetric = shape;
+        for row in 0..3 {
+            for col in 0..3 {
+                *symmetric.at_mut(row, col) = 0.5 * (shape.at(row, col) + shape.at(col, row));
+            }
+        }
+        let (values, vectors) = symmetric.symmetric_eigen();
+
+        let scale = values[0].abs();
+        if values.iter().any(|&value| value <= scale * f64::EPSILON) {
+            return Err(Error::NotPositiveDefinite);
+        }
+
+        let mut axes: [Vector; 3] = core::array::from_fn(|col| {
+            Vector::new(vectors.at(0, col), vectors.at(1, col), vectors.at(2, col))
+        });
+        if axes[0] * axes[1].cross(axes[2]) < 0.0 {
+            axes[2] = -axes[2];
+        }
+
+        let mut rotation = AffineTransform::identity();
+        for (col, axis) in axes.iter().enumerate() {
+            for row in 0..3 {
+                *rotation.at_mut(row, col) = axis.at(row);
+            }
+        }
+
+        Ok(Ellipsoid {
+            center,
+            rotation,
+            curvatures: values.map(|value| 1.0 / value),
+        })
+    }
+
+    // Largest axis aligned ellipsoid inside the box, which must extend along every axis
+    // from min to max
+    pub fn from_bounding_box(min: Point, max: Point) -> Result<Ellipsoid> {
+        let radii: [f64; 3] = core::array::from_fn(|i| 0.5 * (max.at(i) - min.at(i)));
+        if radii
+            .iter()
+            .any(|&radius| !(radius > 0.0 && radius.is_finite()))
+        {
+            return Err(Error::DegenerateBox);
+        }
+
+        let center = Point::new(
+            0.5 * (min.at(0) + max.at(0)),
+            0.5 * (min.at(1) + max.at(1)),
+            0.5 * (min.at(2) + max.at(2)),
+        );
+        Ok(Self::new(center, radii, AffineTransform::identity()))
+    }
+
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    pub fn rotation(&self) -> AffineTransform {
+        self.rotation
+    }
+
+    pub fn curvatures(&self) -> [f64; 3] {
+        self.curvatures
+    }
+
+    pub fn radii(&self) -> [f64; 3] {
+        self.curvatures.map(|curvature| 1.0 / curvature.sqrt())
+    }
+
+    // Principal semi-axes, the rotation columns scaled by the radii
+    pub fn semi_axes(&self) -> [Vector; 3] {
+        let radii = self.radii();
+
+        core::array::from_fn(|i| {
+            Vector::new(
+                self.rotation.at(0, i),
+                self.rotation.at(1, i),
+                self.rotation.at(2, i),
+            ) * radii[i]
+        })
+    }
+
+    // R diag(radii²) R^T
+    pub fn shape_matrix(&self) -> Matrix<f64, 3, 3> {
+        let rotation = self.rotation.submatrix::<3, 3>(0, 0);
+        let radii = self.radii();
+
+        rotation * Matrix::diagonal(&radii.map(|radius| radius * radius)) * rotation.transpose()
+    }
+
+    pub fn bounding_box(&self) -> (Point, Point) {
+        let shape = self.shape_matrix();
+        let extent = Vector::new(
+            shape.at(0, 0).sqrt(),
+            shape.at(1, 1).sqrt(),
+            shape.at(2, 2).sqrt(),
+        );
+
+        (self.center - extent, self.center + extent)
+    }
 }
 
 impl QuadraticForm for Ellipsoid {
     fn quadratic_form_matrix(&self) -> AffineTransform {
-        AffineTransform::diagonal(&[self.a, self.b, self.c, -1.0])
+        let rotation = self.rotation.submatrix::<3, 3>(0, 0);
+        let block = rotation * Matrix::diagonal(&self.curvatures) * rotation.transpose();
+        let center = Vector::to_point(self.center)
+            .as_matrix()
+            .submatrix::<3, 1>(0, 0);
+        let linear = block * center;
+
+        let mut matrix = AffineTransform::zero();
+        for row in 0..3 {
+            for col in 0..3 {
+                *matrix.at_mut(row, col) = block.at(row, col);
+            }
+            *matrix.at_mut(row, 3) = -linear.at(row, 0);
+            *matrix.at_mut(3, row) = -linear.at(row, 0);
+        }
+        *matrix.at_mut(3, 3) = (center.transpose() * linear).num() - 1.0;
+
+        matrix
+    }
+}
+
+// Longitude u and latitude v over the principal semi-axes
+impl ParametricForm for Ellipsoid {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::spherical()
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let [a, b, c] = self.semi_axes();
+        self.center + a * (v.cos() * u.cos()) + b * (v.cos() * u.sin()) + c * v.sin()
+    }
+
+    fn partial_u(&self, u: f64, v: f64) -> Vector {
+        let [a, b, _] = self.semi_axes();
+        a * (-v.cos() * u.sin()) + b * (v.cos() * u.cos())
+    }
+
+    fn partial_v(&self, u: f64, v: f64) -> Vector {
+        let [a, b, c] = self.semi_axes();
+        a * (-v.sin() * u.cos()) + b * (-v.sin() * u.sin()) + c * v.cos()
+    }
+
+    // The rotation may be a reflection, which would turn ∂u × ∂v inward, and the
+    // gradient is also defined at the poles
+    fn normal_at(&self, u: f64, v: f64) -> Vector {
+        self.derivative_at(self.point_at(u, v)).normalized()
+    }
+}
+
+// Bisection runs until the interval no longer shrinks in floating point, which takes
+// about a thousand steps at most
+const MAX_BISECTIONS: usize = 1100;
+
+fn robust_length(values: &[f64]) -> f64 {
+    let scale = values
+        .iter()
+        .fold(0.0, |max: f64, value| max.max(value.abs()));
+    if scale == 0.0 {
+        return 0.0;
+    }
+
+    scale
+        * values
+            .iter()
+            .map(|value| (value / scale).powi(2))
+            .sum::<f64>()
+            .sqrt()
+}
+
+// Root t of Σ (rᵢ zᵢ / (t + rᵢ - 1))² - 1 with r the squared radii over the smallest
+// one, where t - 1 is the multiplier of the closest point in units of the smallest
+// squared radius. Solving for t rather than the multiplier keeps the last denominator
+// from cancelling for points near the center. It lies between z_last and |r z| for points
+// outside, and between z_last and 1 inside.
+fn bisect_root<const N: usize>(ratios: [f64; N], scaled: [f64; N], value: f64) -> f64 {
+    let numerators: [f64; N] = core::array::from_fn(|i| ratios[i] * scaled[i]);
+    let mut low = scaled[N - 1];
+    let mut high = if value < 0.0 {
+        1.0
+    } else {
+        robust_length(&numerators)
+    };
+
+    let mut t = low;
+    for _ in 0..MAX_BISECTIONS {
+        t = 0.5 * (low + high);
+        if t == low || t == high {
+            break;
+        }
+
+        let g = numerators
+            .iter()
+            .zip(ratios)
+            .map(|(n, r)| (n / (t + (r - 1.0))).powi(2))
+            .sum::<f64>()
+            - 1.0;
+        if g > 0.0 {
+            low = t;
+        } else if g < 0.0 {
+            high = t;
+        } else {
+            break;
+        }
+    }
+
+    t
+}
+
+// Closest point to y on the ellipse with radii e0 ≥ e1, for y in the first quadrant
+fn closest_on_ellipse(e: [f64; 2], y: [f64; 2]) -> [f64; 2] {
+    if y[1] > 0.0 {
+        if y[0] > 0.0 {
+            let z = [y[0] / e[0], y[1] / e[1]];
+            let value = z[0] * z[0] + z[1] * z[1] - 1.0;
+            if value == 0.0 {
+                return y;
+            }
+
+            let r = (e[0] / e[1]).powi(2);
+            let t = bisect_root([r, 1.0], z, value);
+            [r * y[0] / (t + (r - 1.0)), y[1] / t]
+        } else {
+            [0.0, e[1]]
+        }
+    } else {
+        // On the major axis, the closest point leaves the axis inside the evolute
+        let numerator = e[0] * y[0];
+        let denominator = e[0] * e[0] - e[1] * e[1];
+        if numerator < denominator {
+            let x = numerator / denominator;
+            [e[0] * x, e[1] * (1.0 - x * x).sqrt()]
+        } else {
+            [e[0], 0.0]
+        }
+    }
+}
+
+// Closest point to y on the ellipsoid with radii e0 ≥ e1 ≥ e2, for y in the first octant,
+// following Eberly, Distance from a Point to an Ellipse, an Ellipsoid, or a Hyperellipsoid
+fn closest_on_ellipsoid(e: [f64; 3], y: [f64; 3]) -> [f64; 3] {
+    if y[2] > 0.0 {
+        if y[1] > 0.0 {
+            if y[0] > 0.0 {
+                let z = [y[0] / e[0], y[1] / e[1], y[2] / e[2]];
+                let value = z.iter().map(|z| z * z).sum::<f64>() - 1.0;
+                if value == 0.0 {
+                    return y;
+                }
+
+                let r = [(e[0] / e[2]).powi(2), (e[1] / e[2]).powi(2), 1.0];
+                let t = bisect_root(r, z, value);
+                core::array::from_fn(|i| r[i] * y[i] / (t + (r[i] - 1.0)))
+            } else {
+                let [x1, x2] = closest_on_ellipse([e[1], e[2]], [y[1], y[2]]);
+                [0.0, x1, x2]
+            }
+        } else if y[0] > 0.0 {
+            let [x0, x2] = closest_on_ellipse([e[0], e[2]], [y[0], y[2]]);
+            [x0, 0.0, x2]
+        } else {
+            [0.0, 0.0, e[2]]
+        }
+    } else {
+        // On the plane of the two largest axes, the closest point leaves the plane when
+        // y is inside the region bounded by the evolute
+        let denominators = [e[0] * e[0] - e[2] * e[2], e[1] * e[1] - e[2] * e[2]];
+        let numerators = [e[0] * y[0], e[1] * y[1]];
+        if numerators[0] < denominators[0] && numerators[1] < denominators[1] {
+            let x = [
+                numerators[0] / denominators[0],
+                numerators[1] / denominators[1],
+            ];
+            let discriminant = 1.0 - x[0] * x[0] - x[1] * x[1];
+            if discriminant > 0.0 {
+                return [e[0] * x[0], e[1] * x[1], e[2] * discriminant.sqrt()];
+            }
+        }
+
+        let [x0, x1] = closest_on_ellipse([e[0], e[1]], [y[0], y[1]]);
+        [x0, x1, 0.0]
+    }
+}
+
+// Exact up to rounding, by reflecting the query into the first octant of the principal
+// frame and sorting the axes by decreasing radius
+impl ClosestPoint for Ellipsoid {
+    fn closest_point(&self, u: Point) -> Closest {
+        let radii = self.radii();
+        let axes: [Vector; 3] = core::array::from_fn(|i| {
+            Vector::new(
+                self.rotation.at(0, i),
+                self.rotation.at(1, i),
+                self.rotation.at(2, i),
+            )
+        });
+        let local: [f64; 3] = core::array::from_fn(|i| axes[i] * (u - self.center));
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| radii[j].total_cmp(&radii[i]));
+        let sorted = closest_on_ellipsoid(order.map(|i| radii[i]), order.map(|i| local[i].abs()));
+
+        let mut point = self.center;
+        for (k, &i) in order.iter().enumerate() {
+            point = point + axes[i] * sorted[k].copysign(local[i]);
+        }
+
+        let distance = (u - point).length();
+        Closest {
+            point,
+            normal: self.derivative_at(point).normalized(),
+            distance: if self.implicit_form_value(u) < 0.0 {
+                -distance
+            } else {
+                distance
+            },
+        }
+    }
+}
+
+impl DistanceForm for Ellipsoid {
+    fn signed_distance(&self, u: Point) -> f64 {
+        self.closest_point(u).distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn bounding_box_gives_the_inscribed_ellipsoid() {
+        let ellipsoid =
+            Ellipsoid::from_bounding_box(Point::new(-1.0, 0.0, 2.0), Point::new(3.0, 1.0, 8.0))
+                .unwrap();
+
+        assert!(
+            ellipsoid
+                .implicit_form_value(Point::new(3.0, 0.5, 5.0))
+                .abs()
+                < 1e-12
+        );
+        assert!(
+            ellipsoid
+                .implicit_form_value(Point::new(1.0, 1.0, 5.0))
+                .abs()
+                < 1e-12
+        );
+        assert!(
+            ellipsoid
+                .implicit_form_value(Point::new(1.0, 0.5, 2.0))
+                .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn bounding_box_rejects_degenerate_boxes() {
+        let (min, max) = (Point::new(0.0, 0.0, 0.0), Point::new(1.0, 1.0, 1.0));
+
+        for corner in [Point::new(1.0, 0.0, 1.0), Point::new(1.0, 1.0, -1.0)] {
+            assert_eq!(
+                Ellipsoid::from_bounding_box(min, corner).unwrap_err(),
+                Error::DegenerateBox
+            );
+        }
+        assert_eq!(
+            Ellipsoid::from_bounding_box(max, min).unwrap_err(),
+            Error::DegenerateBox
+        );
+    }
+
+    fn tilted() -> Ellipsoid {
+        Ellipsoid::new(
+            Point::new(1.0, -2.0, 0.5),
+            [2.0, 3.0, 1.0],
+            rotate_z(0.3) * rotate_x(0.7),
+        )
+    }
+
+    // A grid of queries around the ellipsoid which includes its center and points on
+    // its principal planes and axes
+    fn queries(ellipsoid: &Ellipsoid) -> impl Iterator<Item = Point> + '_ {
+        let [a, b, c] = ellipsoid.semi_axes();
+        (0..343).map(move |index| {
+            let [i, j, k] =
+                [index % 7, (index / 7) % 7, index / 49].map(|i| (i as f64 - 3.0) / 2.0);
+            ellipsoid.center() + a * i + b * j + c * k
+        })
+    }
+
+    fn assert_residuals(ellipsoid: &Ellipsoid, u: Point, closest: &Closest) {
+        let [a, b, c] = ellipsoid.semi_axes();
+        let offset = closest.point - ellipsoid.center();
+        let on_surface = [a, b, c]
+            .iter()
+            .map(|axis| (offset * *axis / (*axis * *axis)).powi(2))
+            .sum::<f64>()
+            - 1.0;
+        assert!(on_surface.abs() < 1e-12);
+
+        let along = u - closest.point;
+        assert!((closest.normal.length() - 1.0).abs() < 1e-12);
+        assert!(along.cross(closest.normal).length() < 1e-9 * (1.0 + along.length()));
+        assert!((along * closest.normal - closest.distance).abs() < 1e-9 * (1.0 + along.length()));
+    }
+
+    #[test]
+    fn closest_points_lie_on_the_surface_along_the_normal() {
+        let ellipsoid = tilted();
+
+        for u in queries(&ellipsoid) {
+            assert_residuals(&ellipsoid, u, &ellipsoid.closest_point(u));
+        }
+    }
+
+    #[test]
+    fn closest_points_are_nearer_than_any_sample() {
+        let ellipsoid = tilted();
+        let samples: Vec<Point> = (0..20000)
+            .map(|index| {
+                let u = (index % 200) as f64 / 200.0 * core::f64::consts::TAU;
+                let v = ((index / 200) as f64 + 0.5) / 100.0 * core::f64::consts::PI
+                    - core::f64::consts::FRAC_PI_2;
+                ellipsoid.point_at(u, v)
+            })
+            .collect();
+
+        for u in queries(&ellipsoid).step_by(17) {
+            let closest = ellipsoid.closest_point(u);
+            let nearest = samples
+                .iter()
+                .map(|&sample| (u - sample).length())
+                .fold(f64::INFINITY, f64::min);
+
+            assert!(closest.distance.abs() <= nearest + 1e-12);
+            assert!(nearest - closest.distance.abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn the_center_is_closest_to_the_ends_of_the_shortest_axis() {
+        let ellipsoid = tilted();
+        let closest = ellipsoid.closest_point(ellipsoid.center());
+
+        assert!((closest.distance + 1.0).abs() < 1e-12);
+        assert!(
+            (closest.point - ellipsoid.center())
+                .cross(ellipsoid.semi_axes()[2])
+                .length()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn projection_agrees_near_the_surface() {
+        let ellipsoid = tilted();
+
+        for (u, v) in [(0.3, 0.2), (2.0, -0.9), (4.0, 1.1), (5.5, 0.0)] {
+            let point = ellipsoid.point_at(u, v);
+            let normal = ellipsoid.normal_at(u, v);
+
+            for offset in [-0.3, 0.2] {
+                let query = point + normal * offset;
+                let exact = ellipsoid.closest_point(query);
+                let projected = project_closest_point(&ellipsoid, query, 1e-12, 100).unwrap();
+
+                assert_residuals(&ellipsoid, query, &projected);
+                assert!((projected.point - exact.point).length() < 1e-8);
+                assert!((projected.distance - exact.distance).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips_the_placed_ellipsoid() {
+        let ellipsoid = Ellipsoid::new(
+            Point::new(1.0, -2.0, 0.5),
+            [3.0, 1.0, 0.25],
+            rotate_z(0.3) * rotate_x(-1.1),
+        );
+        let json = serde_json::to_string(&ellipsoid).unwrap();
+        let read: Ellipsoid = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            read.quadratic_form_matrix().raw(),
+            ellipsoid.quadratic_form_matrix().raw()
+        );
+        for i in 0..3 {
+            assert_eq!(read.center().at(i), ellipsoid.center().at(i));
+        }
+        assert_eq!(serde_json::to_string(&read).unwrap(), json);
     }
 }
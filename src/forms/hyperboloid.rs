@@ -0,0 +1,134 @@
+use crate::{
+    forms::{implicit::*, parametric::*},
+    math::affine::{
+        primitives::{Point, Vector},
+        transforms::*,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HyperboloidOfOneSheet {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl HyperboloidOfOneSheet {
+    pub fn with_curvatures(a: f64, b: f64, c: f64) -> HyperboloidOfOneSheet {
+        HyperboloidOfOneSheet { a, b, c }
+    }
+
+    pub fn with_radii(rx: f64, ry: f64, rz: f64) -> HyperboloidOfOneSheet {
+        Self::with_curvatures(1.0 / (rx * rx), 1.0 / (ry * ry), 1.0 / (rz * rz))
+    }
+}
+
+impl QuadraticForm for HyperboloidOfOneSheet {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        AffineTransform::diagonal(&[self.a, self.b, -self.c, -1.0])
+    }
+}
+
+// Angle u around the z axis and hyperbolic angle v along it
+impl ParametricForm for HyperboloidOfOneSheet {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::around_z([f64::NEG_INFINITY, f64::INFINITY])
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let (rx, ry, rz) = (
+            1.0 / self.a.sqrt(),
+            1.0 / self.b.sqrt(),
+            1.0 / self.c.sqrt(),
+        );
+        Point::new(
+            rx * v.cosh() * u.cos(),
+            ry * v.cosh() * u.sin(),
+            rz * v.sinh(),
+        )
+    }
+
+    fn partial_u(&self, u: f64, v: f64) -> Vector {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Vector::new(-rx * v.cosh() * u.sin(), ry * v.cosh() * u.cos(), 0.0)
+    }
+
+    fn partial_v(&self, u: f64, v: f64) -> Vector {
+        let (rx, ry, rz) = (
+            1.0 / self.a.sqrt(),
+            1.0 / self.b.sqrt(),
+            1.0 / self.c.sqrt(),
+        );
+        Vector::new(
+            rx * v.sinh() * u.cos(),
+            ry * v.sinh() * u.sin(),
+            rz * v.cosh(),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HyperboloidOfTwoSheets {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl HyperboloidOfTwoSheets {
+    pub fn with_curvatures(a: f64, b: f64, c: f64) -> HyperboloidOfTwoSheets {
+        HyperboloidOfTwoSheets { a, b, c }
+    }
+
+    pub fn with_radii(rx: f64, ry: f64, rz: f64) -> HyperboloidOfTwoSheets {
+        Self::with_curvatures(1.0 / (rx * rx), 1.0 / (ry * ry), 1.0 / (rz * rz))
+    }
+}
+
+impl QuadraticForm for HyperboloidOfTwoSheets {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        AffineTransform::diagonal(&[self.a, self.b, -self.c, 1.0])
+    }
+}
+
+// The sheet with positive z, by the angle u around the z axis and the hyperbolic
+// distance v from its vertex, which is singular at v = 0
+impl ParametricForm for HyperboloidOfTwoSheets {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::around_z([0.0, f64::INFINITY])
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let (rx, ry, rz) = (
+            1.0 / self.a.sqrt(),
+            1.0 / self.b.sqrt(),
+            1.0 / self.c.sqrt(),
+        );
+        Point::new(
+            rx * v.sinh() * u.cos(),
+            ry * v.sinh() * u.sin(),
+            rz * v.cosh(),
+        )
+    }
+
+    fn partial_u(&self, u: f64, v: f64) -> Vector {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Vector::new(-rx * v.sinh() * u.sin(), ry * v.sinh() * u.cos(), 0.0)
+    }
+
+    fn partial_v(&self, u: f64, v: f64) -> Vector {
+        let (rx, ry, rz) = (
+            1.0 / self.a.sqrt(),
+            1.0 / self.b.sqrt(),
+            1.0 / self.c.sqrt(),
+        );
+        Vector::new(
+            rx * v.cosh() * u.cos(),
+            ry * v.cosh() * u.sin(),
+            rz * v.sinh(),
+        )
+    }
+}
@@ -1,14 +1,61 @@
-use crate::math::affine::{
-    primitives::{Point, Vector},
-    transforms::AffineTransform,
+use crate::{
+    forms::{
+        canonical::{reduce_quadric, CanonicalQuadric},
+        quadric::{classify_quadric, QuadricType, DEFAULT_CLASSIFICATION_TOLERANCE},
+    },
+    math::{
+        affine::{
+            primitives::{Point, Vector},
+            transforms::AffineTransform,
+        },
+        matrix::Matrix,
+    },
 };
+use alloc::boxed::Box;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointClassification {
+    Inside,
+    On,
+    Outside,
+}
 
 pub trait ImplicitForm {
     fn implicit_form_value(&self, u: Point) -> f64;
 
+    // Whether the point lies on the surface
     fn contains_point(&self, u: Point) -> bool {
-        -std::f64::EPSILON <= self.implicit_form_value(u)
-            || self.implicit_form_value(u) <= std::f64::EPSILON
+        self.implicit_form_value(u).abs() <= f64::EPSILON
+    }
+
+    // Whether the point lies in the solid bounded by the surface, surface included
+    fn contains(&self, u: Point) -> bool {
+        self.implicit_form_value(u) <= 0.0
+    }
+
+    // The value divided by the gradient length approximates the distance to the
+    // surface to first order, so the tolerance is a length independent of how the
+    // form is scaled. At critical points the value itself is compared instead.
+    fn classify_point(&self, u: Point, tolerance: f64) -> PointClassification
+    where
+        Self: DifferentialForm + Sized,
+    {
+        let value = self.implicit_form_value(u);
+        let gradient = self.derivative_at(u).length();
+        let distance = if gradient > 0.0 {
+            value.abs() / gradient
+        } else {
+            value.abs()
+        };
+
+        if distance <= tolerance {
+            PointClassification::On
+        } else if value < 0.0 {
+            PointClassification::Inside
+        } else {
+            PointClassification::Outside
+        }
     }
 }
 
@@ -16,8 +63,32 @@ pub trait DifferentialForm {
     fn derivative_at(&self, u: Point) -> Vector;
 }
 
+pub trait SecondDifferentialForm {
+    fn hessian_at(&self, u: Point) -> Matrix<f64, 3, 3>;
+}
+
 pub trait QuadraticForm {
     fn quadratic_form_matrix(&self) -> AffineTransform;
+
+    fn classify(&self) -> QuadricType {
+        classify_quadric(
+            &self.quadratic_form_matrix(),
+            DEFAULT_CLASSIFICATION_TOLERANCE,
+        )
+    }
+
+    fn canonical_form(&self) -> CanonicalQuadric {
+        reduce_quadric(
+            &self.quadratic_form_matrix(),
+            DEFAULT_CLASSIFICATION_TOLERANCE,
+        )
+    }
+}
+
+impl<F: QuadraticForm + ?Sized> QuadraticForm for Box<F> {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        (**self).quadratic_form_matrix()
+    }
 }
 
 impl<T: QuadraticForm> ImplicitForm for T {
@@ -33,3 +104,10 @@ impl<T: QuadraticForm> DifferentialForm for T {
         Vector::new(vec.at(0, 0), vec.at(0, 1), vec.at(0, 2))
     }
 }
+
+impl<T: QuadraticForm> SecondDifferentialForm for T {
+    fn hessian_at(&self, _u: Point) -> Matrix<f64, 3, 3> {
+        let block = self.quadratic_form_matrix().submatrix::<3, 3>(0, 0);
+        block + block.transpose()
+    }
+}
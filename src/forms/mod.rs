@@ -1,2 +1,22 @@
+pub mod algebraic;
+pub mod canonical;
+pub mod closest;
+pub mod cone;
+pub mod csg;
+pub mod curvature;
+pub mod cylinder;
+pub mod distance;
 pub mod ellipsoid;
+pub mod hyperboloid;
 pub mod implicit;
+pub mod numeric;
+pub mod paraboloid;
+pub mod parametric;
+pub mod planes;
+pub mod polynomial;
+pub mod quadric;
+pub mod ray;
+pub mod section;
+pub mod sphere;
+pub mod superellipsoid;
+pub mod transformed;
@@ -0,0 +1,250 @@
+use crate::{
+    forms::implicit::*,
+    math::{
+        affine::primitives::{Point, Vector},
+        matrix::Matrix,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// Balance truncation against rounding error for central differences of first and
+// second order
+pub fn default_gradient_step() -> f64 {
+    f64::EPSILON.cbrt()
+}
+
+pub fn default_hessian_step() -> f64 {
+    f64::EPSILON.powf(0.25)
+}
+
+fn steps_at(u: Point, relative_step: f64) -> [f64; 3] {
+    core::array::from_fn(|i| relative_step * u.at(i).abs().max(1.0))
+}
+
+fn offset(i: usize, h: f64) -> Vector {
+    let mut delta = [0.0; 3];
+    delta[i] = h;
+    Vector::new(delta[0], delta[1], delta[2])
+}
+
+// Central differences with steps relative to the magnitude of each coordinate
+pub fn numeric_gradient<F: ImplicitForm + ?Sized>(
+    form: &F,
+    u: Point,
+    relative_step: f64,
+) -> Vector {
+    let steps = steps_at(u, relative_step);
+    let partial = |i: usize| {
+        let delta = offset(i, steps[i]);
+        // The step actually taken after rounding of the shifted coordinate
+        let h = (u + delta).at(i) - (u - delta).at(i);
+        (form.implicit_form_value(u + delta) - form.implicit_form_value(u - delta)) / h
+    };
+
+    Vector::new(partial(0), partial(1), partial(2))
+}
+
+pub fn numeric_hessian<F: ImplicitForm + ?Sized>(
+    form: &F,
+    u: Point,
+    relative_step: f64,
+) -> Matrix<f64, 3, 3> {
+    let steps = steps_at(u, relative_step);
+    let value = |point: Point| form.implicit_form_value(point);
+
+    let center = value(u);
+    let mut hessian = Matrix::zero();
+
+    for i in 0..3 {
+        let di = offset(i, steps[i]);
+        *hessian.at_mut(i, i) =
+            (value(u + di) - 2.0 * center + value(u - di)) / (steps[i] * steps[i]);
+
+        for j in 0..i {
+            let dj = offset(j, steps[j]);
+            let mixed = (value(u + di + dj) - value(u + di - dj) - value(u - di + dj)
+                + value(u - di - dj))
+                / (4.0 * steps[i] * steps[j]);
+
+            *hessian.at_mut(i, j) = mixed;
+            *hessian.at_mut(j, i) = mixed;
+        }
+    }
+
+    hessian
+}
+
+// Gives any implicit form the derivatives it does not have analytically, estimated by
+// finite differences
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Numeric<F> {
+    form: F,
+    relative_step: Option<f64>,
+}
+
+impl<F> Numeric<F> {
+    // Uses the default step of each derivative order
+    pub fn new(form: F) -> Numeric<F> {
+        Numeric {
+            form,
+            relative_step: None,
+        }
+    }
+
+    pub fn with_step(form: F, relative_step: f64) -> Numeric<F> {
+        Numeric {
+            form,
+            relative_step: Some(relative_step),
+        }
+    }
+
+    pub fn form(&self) -> &F {
+        &self.form
+    }
+}
+
+impl<F: ImplicitForm> ImplicitForm for Numeric<F> {
+    fn implicit_form_value(&self, u: Point) -> f64 {
+        self.form.implicit_form_value(u)
+    }
+}
+
+impl<F: ImplicitForm> DifferentialForm for Numeric<F> {
+    fn derivative_at(&self, u: Point) -> Vector {
+        let step = self.relative_step.unwrap_or_else(default_gradient_step);
+        numeric_gradient(&self.form, u, step)
+    }
+}
+
+impl<F: ImplicitForm> SecondDifferentialForm for Numeric<F> {
+    fn hessian_at(&self, u: Point) -> Matrix<f64, 3, 3> {
+        let step = self.relative_step.unwrap_or_else(default_hessian_step);
+        numeric_hessian(&self.form, u, step)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GradientCheck {
+    pub point: Point,
+    pub analytic: Vector,
+    pub numeric: Vector,
+    pub relative_error: f64,
+}
+
+pub fn check_gradient<F: ImplicitForm + DifferentialForm + ?Sized>(
+    form: &F,
+    u: Point,
+) -> GradientCheck {
+    let analytic = form.derivative_at(u);
+    let numeric = numeric_gradient(form, u, default_gradient_step());
+    let scale = analytic.length().max(numeric.length());
+    let relative_error = if scale > 0.0 {
+        (analytic - numeric).length() / scale
+    } else {
+        0.0
+    };
+
+    GradientCheck {
+        point: u,
+        analytic,
+        numeric,
+        relative_error,
+    }
+}
+
+// The check with the largest relative error over the points, for validating the
+// analytic derivatives of new forms
+pub fn gradient_check<F, I>(form: &F, points: I) -> Option<GradientCheck>
+where
+    F: ImplicitForm + DifferentialForm + ?Sized,
+    I: IntoIterator<Item = Point>,
+{
+    points
+        .into_iter()
+        .map(|u| check_gradient(form, u))
+        .max_by(|a, b| a.relative_error.total_cmp(&b.relative_error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::quadric::GeneralQuadric;
+
+    const COEFFICIENTS: [f64; 10] = [2.0, -1.0, 0.5, 0.3, -1.2, 0.8, 1.5, -0.4, 2.2, -3.0];
+
+    fn points() -> [Point; 4] {
+        [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, -2.0, 0.5),
+            Point::new(-3.5, 0.25, 4.0),
+            Point::new(120.0, -80.0, 45.0),
+        ]
+    }
+
+    // Differences of a quadratic have no truncation error, so what is left is the
+    // rounding of values as large as this, divided by the step once per order
+    fn rounding_bound(u: Point) -> f64 {
+        let magnitude = (0..3).map(|i| u.at(i).abs()).fold(1.0, f64::max);
+        let coefficients: f64 = COEFFICIENTS.iter().map(|c| c.abs()).sum();
+        16.0 * f64::EPSILON * coefficients * magnitude * magnitude
+    }
+
+    #[test]
+    fn gradients_match_the_analytic_quadric_gradient() {
+        let quadric = GeneralQuadric::from_coefficients(COEFFICIENTS);
+
+        for step in [default_gradient_step(), 1e-3, 1e-7] {
+            for u in points() {
+                let analytic = quadric.derivative_at(u);
+                let numeric = numeric_gradient(&quadric, u, step);
+                let h = step * (0..3).map(|i| u.at(i).abs()).fold(1.0, f64::max);
+
+                assert!((analytic - numeric).length() <= rounding_bound(u) / h);
+            }
+        }
+    }
+
+    #[test]
+    fn hessians_match_the_analytic_quadric_hessian() {
+        let quadric = GeneralQuadric::from_coefficients(COEFFICIENTS);
+
+        for step in [default_hessian_step(), 1e-2, 1e-5] {
+            for u in points() {
+                let analytic = quadric.hessian_at(u);
+                let numeric = numeric_hessian(&quadric, u, step);
+                let h = step * (0..3).map(|i| u.at(i).abs()).fold(1.0, f64::max);
+
+                for i in 0..3 {
+                    for j in 0..3 {
+                        let error = (analytic.at(i, j) - numeric.at(i, j)).abs();
+                        assert!(error <= rounding_bound(u) / (h * h));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_forms_agree_with_the_analytic_derivatives() {
+        let quadric = GeneralQuadric::from_coefficients(COEFFICIENTS);
+        let numeric = Numeric::new(quadric);
+
+        for u in points() {
+            let h = (0..3).map(|i| u.at(i).abs()).fold(1.0, f64::max);
+            let gradient = default_gradient_step() * h;
+            let hessian = default_hessian_step() * h;
+
+            let error = (numeric.derivative_at(u) - quadric.derivative_at(u)).length();
+            assert!(error <= rounding_bound(u) / gradient);
+            let (numeric, analytic) = (numeric.hessian_at(u), quadric.hessian_at(u));
+            for (a, b) in numeric.raw().iter().zip(analytic.raw()) {
+                assert!((a - b).abs() <= rounding_bound(u) / (hessian * hessian));
+            }
+        }
+
+        let check = gradient_check(&quadric, points()).unwrap();
+        assert!(check.relative_error < 1e-9);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    forms::{implicit::*, parametric::*},
+    math::affine::{
+        primitives::{Point, Vector},
+        transforms::*,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+fn paraboloid_matrix(a: f64, b: f64) -> AffineTransform {
+    let mut matrix = AffineTransform::diagonal(&[a, b, 0.0, 0.0]);
+
+    *matrix.at_mut(2, 3) = -0.5;
+    *matrix.at_mut(3, 2) = -0.5;
+
+    matrix
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EllipticParaboloid {
+    a: f64,
+    b: f64,
+}
+
+impl EllipticParaboloid {
+    pub fn with_curvatures(a: f64, b: f64) -> EllipticParaboloid {
+        EllipticParaboloid { a, b }
+    }
+
+    pub fn with_radii(rx: f64, ry: f64) -> EllipticParaboloid {
+        Self::with_curvatures(1.0 / (rx * rx), 1.0 / (ry * ry))
+    }
+}
+
+impl QuadraticForm for EllipticParaboloid {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        paraboloid_matrix(self.a, self.b)
+    }
+}
+
+// Angle u around the z axis and v with height v², singular at the vertex
+impl ParametricForm for EllipticParaboloid {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::around_z([0.0, f64::INFINITY])
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Point::new(rx * v * u.cos(), ry * v * u.sin(), v * v)
+    }
+
+    fn partial_u(&self, u: f64, v: f64) -> Vector {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Vector::new(-rx * v * u.sin(), ry * v * u.cos(), 0.0)
+    }
+
+    fn partial_v(&self, u: f64, v: f64) -> Vector {
+        let (rx, ry) = (1.0 / self.a.sqrt(), 1.0 / self.b.sqrt());
+        Vector::new(rx * u.cos(), ry * u.sin(), 2.0 * v)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HyperbolicParaboloid {
+    a: f64,
+    b: f64,
+}
+
+impl HyperbolicParaboloid {
+    pub fn with_curvatures(a: f64, b: f64) -> HyperbolicParaboloid {
+        HyperbolicParaboloid { a, b }
+    }
+
+    pub fn with_radii(rx: f64, ry: f64) -> HyperbolicParaboloid {
+        Self::with_curvatures(1.0 / (rx * rx), 1.0 / (ry * ry))
+    }
+}
+
+impl QuadraticForm for HyperbolicParaboloid {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        paraboloid_matrix(self.a, -self.b)
+    }
+}
+
+// The graph z = a x² - b y² over u = x and v = y, whose normal ∂u × ∂v points up,
+// against the gradient
+impl ParametricForm for HyperbolicParaboloid {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::new(
+            [f64::NEG_INFINITY, f64::INFINITY],
+            [f64::NEG_INFINITY, f64::INFINITY],
+        )
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        Point::new(u, v, self.a * u * u - self.b * v * v)
+    }
+
+    fn partial_u(&self, u: f64, _v: f64) -> Vector {
+        Vector::new(1.0, 0.0, 2.0 * self.a * u)
+    }
+
+    fn partial_v(&self, _u: f64, v: f64) -> Vector {
+        Vector::new(0.0, 1.0, -2.0 * self.b * v)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    forms::transformed::Transformed,
+    math::affine::primitives::{Point, Vector},
+};
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_PI_2, PI};
+
+// Rectangle of parameters covering the surface. Unbounded surfaces have infinite
+// ranges, which have to be bounded before sampling.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParameterDomain {
+    pub u: [f64; 2],
+    pub v: [f64; 2],
+    // Whether the surface closes up across the ends of the range
+    pub periodic_u: bool,
+    pub periodic_v: bool,
+}
+
+impl ParameterDomain {
+    pub fn new(u: [f64; 2], v: [f64; 2]) -> ParameterDomain {
+        ParameterDomain {
+            u,
+            v,
+            periodic_u: false,
+            periodic_v: false,
+        }
+    }
+
+    // Longitude around the z axis in [-π, π] against the given range
+    pub(crate) fn around_z(v: [f64; 2]) -> ParameterDomain {
+        ParameterDomain {
+            periodic_u: true,
+            ..Self::new([-PI, PI], v)
+        }
+    }
+
+    // Longitude in [-π, π] against latitude in [-π/2, π/2]
+    pub(crate) fn spherical() -> ParameterDomain {
+        Self::around_z([-FRAC_PI_2, FRAC_PI_2])
+    }
+
+    pub fn is_bounded(&self) -> bool {
+        self.u.iter().chain(&self.v).all(|bound| bound.is_finite())
+    }
+
+    // Replaces infinite bounds by the limit with the same sign
+    pub fn bounded(&self, limit: f64) -> ParameterDomain {
+        let bound = |value: f64| value.clamp(-limit, limit);
+
+        ParameterDomain {
+            u: self.u.map(bound),
+            v: self.v.map(bound),
+            ..*self
+        }
+    }
+
+    // Parameters rescaled to [0, 1]², as used for texture coordinates
+    pub fn normalize(&self, u: f64, v: f64) -> (f64, f64) {
+        (
+            (u - self.u[0]) / (self.u[1] - self.u[0]),
+            (v - self.v[0]) / (self.v[1] - self.v[0]),
+        )
+    }
+}
+
+// Surfaces given by a map from parameters (u, v) to points. The parametrization may be
+// singular, as at the poles of a sphere, where the partial derivatives are dependent.
+pub trait ParametricForm {
+    fn domain(&self) -> ParameterDomain;
+    fn point_at(&self, u: f64, v: f64) -> Point;
+    fn partial_u(&self, u: f64, v: f64) -> Vector;
+    fn partial_v(&self, u: f64, v: f64) -> Vector;
+
+    // Unit normal ∂u × ∂v, which follows the gradient of the forms unless noted
+    fn normal_at(&self, u: f64, v: f64) -> Vector {
+        self.partial_u(u, v)
+            .cross(self.partial_v(u, v))
+            .normalized()
+    }
+}
+
+impl<F: ParametricForm> ParametricForm for Transformed<F> {
+    fn domain(&self) -> ParameterDomain {
+        self.form().domain()
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        self.transform() * self.form().point_at(u, v)
+    }
+
+    fn partial_u(&self, u: f64, v: f64) -> Vector {
+        self.transform() * self.form().partial_u(u, v)
+    }
+
+    fn partial_v(&self, u: f64, v: f64) -> Vector {
+        self.transform() * self.form().partial_v(u, v)
+    }
+}
+
+// Evenly spaced values over the range including both ends
+fn samples_in(range: [f64; 2], count: usize) -> impl Iterator<Item = f64> {
+    let last = count.saturating_sub(1).max(1) as f64;
+    (0..count).map(move |i| range[0] + (range[1] - range[0]) * i as f64 / last)
+}
+
+// Points of the line of constant u over the range of v
+pub fn iso_u_line<F: ParametricForm + ?Sized>(
+    form: &F,
+    u: f64,
+    v_range: [f64; 2],
+    samples: usize,
+) -> Vec<Point> {
+    samples_in(v_range, samples)
+        .map(|v| form.point_at(u, v))
+        .collect()
+}
+
+// Points of the line of constant v over the range of u
+pub fn iso_v_line<F: ParametricForm + ?Sized>(
+    form: &F,
+    v: f64,
+    u_range: [f64; 2],
+    samples: usize,
+) -> Vec<Point> {
+    samples_in(u_range, samples)
+        .map(|u| form.point_at(u, v))
+        .collect()
+}
+
+// Grid of points over the domain in rows of constant v, including both ends of each
+// range so that seams of periodic directions appear twice, as texture mapping needs
+pub fn sample_grid<F: ParametricForm + ?Sized>(
+    form: &F,
+    domain: &ParameterDomain,
+    u_samples: usize,
+    v_samples: usize,
+) -> Vec<Point> {
+    samples_in(domain.v, v_samples)
+        .flat_map(|v| samples_in(domain.u, u_samples).map(move |u| form.point_at(u, v)))
+        .collect()
+}
@@ -0,0 +1,56 @@
+use crate::{forms::implicit::*, math::affine::transforms::*};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntersectingPlanes {
+    a: f64,
+    b: f64,
+}
+
+impl IntersectingPlanes {
+    pub fn with_curvatures(a: f64, b: f64) -> IntersectingPlanes {
+        IntersectingPlanes { a, b }
+    }
+
+    pub fn with_half_angle(angle: f64) -> IntersectingPlanes {
+        let slope = angle.tan();
+        Self::with_curvatures(slope * slope, 1.0)
+    }
+}
+
+impl QuadraticForm for IntersectingPlanes {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        AffineTransform::diagonal(&[self.a, -self.b, 0.0, 0.0])
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParallelPlanes {
+    distance: f64,
+}
+
+impl ParallelPlanes {
+    pub fn with_distance(distance: f64) -> ParallelPlanes {
+        ParallelPlanes { distance }
+    }
+}
+
+impl QuadraticForm for ParallelPlanes {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        let half_distance = 0.5 * self.distance;
+        AffineTransform::diagonal(&[1.0, 0.0, 0.0, -half_distance * half_distance])
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoincidentPlanes;
+
+impl QuadraticForm for CoincidentPlanes {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        AffineTransform::diagonal(&[1.0, 0.0, 0.0, 0.0])
+    }
+}
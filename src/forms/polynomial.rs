@@ -0,0 +1,421 @@
+use crate::{
+    error::Result,
+    forms::implicit::*,
+    math::{
+        affine::{
+            primitives::{Point, Vector},
+            transforms::*,
+        },
+        matrix::Matrix,
+    },
+};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// coefficient * x^i * y^j * z^k for exponents [i, j, k]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Monomial {
+    coefficient: f64,
+    exponents: [u32; 3],
+}
+
+impl Monomial {
+    pub fn new(coefficient: f64, exponents: [u32; 3]) -> Monomial {
+        Monomial {
+            coefficient,
+            exponents,
+        }
+    }
+
+    pub fn coefficient(&self) -> f64 {
+        self.coefficient
+    }
+
+    pub fn exponents(&self) -> [u32; 3] {
+        self.exponents
+    }
+
+    pub fn degree(&self) -> u32 {
+        self.exponents.iter().sum()
+    }
+
+    // Value of the partial derivative of the given orders in x, y and z
+    fn derivative_value(&self, u: [f64; 3], orders: [u32; 3]) -> f64 {
+        let mut value = self.coefficient;
+
+        for axis in 0..3 {
+            let (exponent, order) = (self.exponents[axis], orders[axis]);
+            if order > exponent {
+                return 0.0;
+            }
+
+            // Falling factorial exponent * (exponent - 1) * ... over the order
+            for factor in (exponent - order + 1)..=exponent {
+                value *= factor as f64;
+            }
+            value *= u[axis].powi((exponent - order) as i32);
+        }
+
+        value
+    }
+}
+
+// Sparse trivariate polynomial, kept with like terms merged, zero terms dropped and
+// terms sorted by exponents
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polynomial {
+    terms: Vec<Monomial>,
+}
+
+impl Polynomial {
+    pub fn new(mut terms: Vec<Monomial>) -> Polynomial {
+        terms.sort_by_key(|term| term.exponents);
+
+        let mut merged: Vec<Monomial> = Vec::with_capacity(terms.len());
+        for term in terms {
+            match merged.last_mut() {
+                Some(last) if last.exponents == term.exponents => {
+                    last.coefficient += term.coefficient
+                }
+                _ => merged.push(term),
+            }
+        }
+        merged.retain(|term| term.coefficient != 0.0);
+
+        Polynomial { terms: merged }
+    }
+
+    pub fn zero() -> Polynomial {
+        Polynomial { terms: Vec::new() }
+    }
+
+    pub fn constant(value: f64) -> Polynomial {
+        Self::new(vec![Monomial::new(value, [0, 0, 0])])
+    }
+
+    // The coordinate of the given axis, 0 for x, 1 for y and 2 for z
+    pub fn variable(axis: usize) -> Polynomial {
+        let mut exponents = [0; 3];
+        exponents[axis] = 1;
+
+        Self::new(vec![Monomial::new(1.0, exponents)])
+    }
+
+    // uᵀ M u for u = (x, y, z, 1)
+    pub fn from_quadratic_form<F: QuadraticForm + ?Sized>(form: &F) -> Polynomial {
+        let matrix = form.quadratic_form_matrix();
+        let mut terms = Vec::with_capacity(16);
+
+        for row in 0..4 {
+            for col in 0..4 {
+                let mut exponents = [0; 3];
+                for axis in [row, col] {
+                    if axis < 3 {
+                        exponents[axis] += 1;
+                    }
+                }
+                terms.push(Monomial::new(matrix.at(row, col), exponents));
+            }
+        }
+
+        Self::new(terms)
+    }
+
+    pub fn terms(&self) -> &[Monomial] {
+        &self.terms
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn degree(&self) -> u32 {
+        self.terms.iter().map(Monomial::degree).max().unwrap_or(0)
+    }
+
+    pub fn powi(&self, exponent: u32) -> Polynomial {
+        (0..exponent).fold(Self::constant(1.0), |power, _| &power * self)
+    }
+
+    pub fn partial_derivative(&self, axis: usize) -> Polynomial {
+        Self::new(
+            self.terms
+                .iter()
+                .filter(|term| term.exponents[axis] > 0)
+                .map(|term| {
+                    let mut exponents = term.exponents;
+                    exponents[axis] -= 1;
+                    Monomial::new(term.coefficient * term.exponents[axis] as f64, exponents)
+                })
+                .collect(),
+        )
+    }
+
+    // The polynomial p(T u), substituting each coordinate by its affine image
+    pub fn compose(&self, transform: &AffineTransform) -> Polynomial {
+        let images: [Polynomial; 3] = core::array::from_fn(|row| {
+            Self::new(vec![
+                Monomial::new(transform.at(row, 0), [1, 0, 0]),
+                Monomial::new(transform.at(row, 1), [0, 1, 0]),
+                Monomial::new(transform.at(row, 2), [0, 0, 1]),
+                Monomial::new(transform.at(row, 3), [0, 0, 0]),
+            ])
+        });
+
+        // Powers of the images up to the degree, shared between the terms
+        let degree = self.degree() as usize;
+        let powers: [Vec<Polynomial>; 3] = core::array::from_fn(|axis| {
+            let mut powers = vec![Self::constant(1.0)];
+            for power in 1..=degree {
+                let next = &powers[power - 1] * &images[axis];
+                powers.push(next);
+            }
+            powers
+        });
+
+        self.terms.iter().fold(Self::zero(), |sum, term| {
+            let [i, j, k] = term.exponents.map(|exponent| exponent as usize);
+            let product = &(&powers[0][i] * &powers[1][j]) * &powers[2][k];
+            sum + product * term.coefficient
+        })
+    }
+
+    // The polynomial of the surface moved by the transform, the analogue of the
+    // congruence for quadrics
+    pub fn transformed(&self, transform: &AffineTransform) -> Result<Polynomial> {
+        Ok(self.compose(&transform.try_inverse()?))
+    }
+
+    fn derivative_value(&self, u: Point, orders: [u32; 3]) -> f64 {
+        let u = [u.at(0), u.at(1), u.at(2)];
+        self.terms
+            .iter()
+            .map(|term| term.derivative_value(u, orders))
+            .sum()
+    }
+}
+
+impl ImplicitForm for Polynomial {
+    fn implicit_form_value(&self, u: Point) -> f64 {
+        self.derivative_value(u, [0, 0, 0])
+    }
+}
+
+impl DifferentialForm for Polynomial {
+    fn derivative_at(&self, u: Point) -> Vector {
+        Vector::new(
+            self.derivative_value(u, [1, 0, 0]),
+            self.derivative_value(u, [0, 1, 0]),
+            self.derivative_value(u, [0, 0, 1]),
+        )
+    }
+}
+
+impl SecondDifferentialForm for Polynomial {
+    fn hessian_at(&self, u: Point) -> Matrix<f64, 3, 3> {
+        let mut hessian = Matrix::<f64, 3, 3>::zero();
+
+        for row in 0..3 {
+            for col in row..3 {
+                let mut orders = [0; 3];
+                orders[row] += 1;
+                orders[col] += 1;
+
+                let value = self.derivative_value(u, orders);
+                *hessian.at_mut(row, col) = value;
+                *hessian.at_mut(col, row) = value;
+            }
+        }
+
+        hessian
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: Polynomial) -> Polynomial {
+        let mut terms = self.terms;
+        terms.extend(other.terms);
+
+        Polynomial::new(terms)
+    }
+}
+
+impl Sub for Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: Polynomial) -> Polynomial {
+        self + -other
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        self * -1.0
+    }
+}
+
+impl Mul<f64> for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, factor: f64) -> Polynomial {
+        Polynomial::new(
+            self.terms
+                .into_iter()
+                .map(|term| Monomial::new(term.coefficient * factor, term.exponents))
+                .collect(),
+        )
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: &Polynomial) -> Polynomial {
+        let mut terms = Vec::with_capacity(self.terms.len() * other.terms.len());
+
+        for first in &self.terms {
+            for second in &other.terms {
+                let exponents: [u32; 3] =
+                    core::array::from_fn(|axis| first.exponents[axis] + second.exponents[axis]);
+                terms.push(Monomial::new(
+                    first.coefficient * second.coefficient,
+                    exponents,
+                ));
+            }
+        }
+
+        Polynomial::new(terms)
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: Polynomial) -> Polynomial {
+        &self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::{algebraic::Torus, sphere::Sphere};
+
+    const POINTS: [[f64; 3]; 4] = [
+        [0.0, 0.0, 0.0],
+        [1.0, -2.0, 0.5],
+        [-0.3, 0.7, 2.0],
+        [3.0, 1.5, -1.0],
+    ];
+
+    fn points() -> impl Iterator<Item = Point> {
+        POINTS.iter().map(|&[x, y, z]| Point::new(x, y, z))
+    }
+
+    #[test]
+    fn new_merges_like_terms_and_drops_zeros() {
+        let polynomial = Polynomial::new(vec![
+            Monomial::new(2.0, [0, 1, 0]),
+            Monomial::new(1.0, [1, 0, 0]),
+            Monomial::new(-2.0, [0, 1, 0]),
+            Monomial::new(3.0, [1, 0, 0]),
+            Monomial::new(0.0, [0, 0, 5]),
+        ]);
+
+        assert_eq!(polynomial.terms(), &[Monomial::new(4.0, [1, 0, 0])]);
+        assert_eq!(polynomial.degree(), 1);
+        assert!((polynomial.clone() - polynomial).is_zero());
+    }
+
+    #[test]
+    fn quadratic_forms_keep_their_values() {
+        let sphere = Sphere::with_radius(2.0);
+        let polynomial = Polynomial::from_quadratic_form(&sphere);
+
+        assert_eq!(polynomial.degree(), 2);
+        for point in points() {
+            let expected = sphere.implicit_form_value(point);
+            assert!((polynomial.implicit_form_value(point) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn derivatives_match_finite_differences() {
+        let torus = Torus::new(2.0, 0.5);
+        let polynomial = torus.polynomial();
+        let step = 1e-5;
+
+        for point in points() {
+            let gradient = polynomial.derivative_at(point);
+            let hessian = polynomial.hessian_at(point);
+
+            for axis in 0..3 {
+                let mut offset = Vector::new(0.0, 0.0, 0.0);
+                *offset.at_mut(axis) = step;
+
+                let difference = (polynomial.implicit_form_value(point + offset)
+                    - polynomial.implicit_form_value(point - offset))
+                    / (2.0 * step);
+                assert!((gradient.at(axis) - difference).abs() < 1e-4 * (1.0 + difference.abs()));
+
+                let partial = polynomial.partial_derivative(axis);
+                assert!((partial.implicit_form_value(point) - gradient.at(axis)).abs() < 1e-9);
+
+                let second = polynomial.derivative_at(point + offset)
+                    - polynomial.derivative_at(point - offset);
+                for row in 0..3 {
+                    let difference = second.at(row) / (2.0 * step);
+                    assert!(
+                        (hessian.at(row, axis) - difference).abs()
+                            < 1e-4 * (1.0 + difference.abs())
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn torus_vanishes_on_its_tube() {
+        let (major, minor) = (2.0, 0.5);
+        let torus = Torus::new(major, minor);
+
+        for (u, v) in [(0.0, 0.0), (1.0, 2.0), (-2.5, 0.3), (4.0, -1.2)] {
+            let radial = major + minor * f64::cos(v);
+            let point = Point::new(
+                radial * f64::cos(u),
+                radial * f64::sin(u),
+                minor * f64::sin(v),
+            );
+            assert!(torus.implicit_form_value(point).abs() < 1e-12);
+        }
+        assert!(torus.implicit_form_value(Point::new(0.0, 0.0, 0.0)) > 0.0);
+        assert!(torus.implicit_form_value(Point::new(major, 0.0, 0.0)) < 0.0);
+    }
+
+    #[test]
+    fn transformed_moves_the_surface() {
+        let polynomial = Polynomial::from_quadratic_form(&Sphere::with_radius(1.0));
+        let transform = translate(Vector::new(1.0, -2.0, 3.0)) * scale(2.0, 1.0, 1.0);
+        let moved = polynomial.transformed(&transform).unwrap();
+
+        for point in points() {
+            let expected = polynomial.implicit_form_value(point);
+            assert!((moved.implicit_form_value(transform * point) - expected).abs() < 1e-12);
+            assert!(
+                (polynomial.compose(&transform).implicit_form_value(point)
+                    - polynomial.implicit_form_value(transform * point))
+                .abs()
+                    < 1e-12
+            );
+        }
+        assert!(polynomial.transformed(&scale(1.0, 0.0, 1.0)).is_err());
+    }
+}
@@ -0,0 +1,371 @@
+use crate::{
+    forms::implicit::*,
+    math::{affine::transforms::*, matrix::Matrix},
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+pub const DEFAULT_CLASSIFICATION_TOLERANCE: f64 = 1e-10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuadricType {
+    RealEllipsoid,
+    ImaginaryEllipsoid,
+    HyperboloidOfOneSheet,
+    HyperboloidOfTwoSheets,
+    RealEllipticCone,
+    ImaginaryEllipticCone,
+    EllipticParaboloid,
+    HyperbolicParaboloid,
+    RealEllipticCylinder,
+    ImaginaryEllipticCylinder,
+    HyperbolicCylinder,
+    ParabolicCylinder,
+    RealIntersectingPlanes,
+    ImaginaryIntersectingPlanes,
+    RealParallelPlanes,
+    ImaginaryParallelPlanes,
+    CoincidentPlanes,
+    SinglePlane,
+    EmptySet,
+    WholeSpace,
+}
+
+// Number of positive and negative values, ignoring those within the threshold of zero
+pub(crate) fn inertia(values: &[f64], threshold: f64) -> (usize, usize) {
+    let positive = values.iter().filter(|&&value| value > threshold).count();
+    let negative = values.iter().filter(|&&value| value < -threshold).count();
+    (positive, negative)
+}
+
+// Factor s for the homogeneous coordinate that brings the linear and constant terms
+// to the size of the quadratic block, or the constant term to the size of the linear
+// one when there is no quadratic block
+pub(crate) fn homogeneous_scale<const M: usize>(symmetric: &Matrix<f64, M, M>) -> f64 {
+    let last = M - 1;
+    let mut quadratic = 0.0_f64;
+    let mut linear = 0.0_f64;
+    for row in 0..last {
+        linear = linear.max(symmetric.at(row, last).abs());
+        for col in 0..last {
+            quadratic = quadratic.max(symmetric.at(row, col).abs());
+        }
+    }
+    let constant = symmetric.at(last, last).abs();
+
+    let scale = if quadratic > 0.0 {
+        let by_linear = if linear > 0.0 {
+            quadratic / linear
+        } else {
+            f64::INFINITY
+        };
+        let by_constant = if constant > 0.0 {
+            (quadratic / constant).sqrt()
+        } else {
+            f64::INFINITY
+        };
+        by_linear.min(by_constant)
+    } else {
+        linear / constant
+    };
+
+    if scale.is_finite() && scale > 0.0 {
+        scale
+    } else {
+        1.0
+    }
+}
+
+// The congruence by diag(1, ..., 1, s), which keeps the rank and the signature
+pub(crate) fn balanced<const M: usize>(
+    symmetric: &Matrix<f64, M, M>,
+    scale: f64,
+) -> Matrix<f64, M, M> {
+    let mut balanced = *symmetric;
+
+    for i in 0..M {
+        *balanced.at_mut(i, M - 1) *= scale;
+        *balanced.at_mut(M - 1, i) *= scale;
+    }
+
+    balanced
+}
+
+// Affine classification by rank and signature of the symmetric matrix and its
+// quadratic 3x3 block, with eigenvalues relative to the largest one treated as zero
+// when they are below the tolerance. The homogeneous coordinate is balanced first, so
+// that the result does not depend on the scale of the coordinates.
+pub fn classify_quadric(matrix: &AffineTransform, tolerance: f64) -> QuadricType {
+    let symmetric = symmetrized(matrix);
+    let symmetric = balanced(&symmetric, homogeneous_scale(&symmetric));
+    let (values4, _) = symmetric.symmetric_eigen();
+    let (values3, _) = symmetric.submatrix::<3, 3>(0, 0).symmetric_eigen();
+
+    let scale = values4
+        .iter()
+        .fold(0.0_f64, |max, value| max.max(value.abs()));
+    if scale == 0.0 {
+        return QuadricType::WholeSpace;
+    }
+
+    let threshold = tolerance * scale;
+    let (mut positive3, mut negative3) = inertia(&values3, threshold);
+    let (mut positive4, mut negative4) = inertia(&values4, threshold);
+
+    // The equation can be multiplied by -1, so only the dominant sign matters
+    if negative3 > positive3 || (negative3 == positive3 && negative4 > positive4) {
+        core::mem::swap(&mut positive3, &mut negative3);
+        core::mem::swap(&mut positive4, &mut negative4);
+    }
+
+    match (positive3 + negative3, positive4 + negative4) {
+        (3, 4) => match (negative3, negative4) {
+            (0, 1) => QuadricType::RealEllipsoid,
+            (0, _) => QuadricType::ImaginaryEllipsoid,
+            (_, 2) => QuadricType::HyperboloidOfOneSheet,
+            _ => QuadricType::HyperboloidOfTwoSheets,
+        },
+        (3, _) if negative3 == 0 => QuadricType::ImaginaryEllipticCone,
+        (3, _) => QuadricType::RealEllipticCone,
+        (2, 4) if negative3 == 0 => QuadricType::EllipticParaboloid,
+        (2, 4) => QuadricType::HyperbolicParaboloid,
+        (2, 3) => match (negative3, negative4) {
+            (0, 1) => QuadricType::RealEllipticCylinder,
+            (0, _) => QuadricType::ImaginaryEllipticCylinder,
+            _ => QuadricType::HyperbolicCylinder,
+        },
+        (2, _) if negative3 == 0 => QuadricType::ImaginaryIntersectingPlanes,
+        (2, _) => QuadricType::RealIntersectingPlanes,
+        (1, 3..) => QuadricType::ParabolicCylinder,
+        (1, 2) if negative4 == 1 => QuadricType::RealParallelPlanes,
+        (1, 2) => QuadricType::ImaginaryParallelPlanes,
+        (1, _) => QuadricType::CoincidentPlanes,
+        (_, 2..) => QuadricType::SinglePlane,
+        (_, 1) => QuadricType::EmptySet,
+        _ => QuadricType::WholeSpace,
+    }
+}
+
+pub(crate) fn symmetrized(matrix: &AffineTransform) -> AffineTransform {
+    let mut symmetric = *matrix;
+
+    for row in 0..4 {
+        for col in 0..4 {
+            *symmetric.at_mut(row, col) = 0.5 * (matrix.at(row, col) + matrix.at(col, row));
+        }
+    }
+
+    symmetric
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneralQuadric {
+    matrix: AffineTransform,
+}
+
+impl GeneralQuadric {
+    // Coefficients of Ax² + By² + Cz² + Dxy + Eyz + Fxz + Gx + Hy + Iz + J = 0
+    pub fn from_coefficients(coefficients: [f64; 10]) -> GeneralQuadric {
+        let [a, b, c, d, e, f, g, h, i, j] = coefficients;
+
+        GeneralQuadric {
+            matrix: AffineTransform::from_data([
+                [a, 0.5 * d, 0.5 * f, 0.5 * g],
+                [0.5 * d, b, 0.5 * e, 0.5 * h],
+                [0.5 * f, 0.5 * e, c, 0.5 * i],
+                [0.5 * g, 0.5 * h, 0.5 * i, j],
+            ]),
+        }
+    }
+
+    pub fn from_matrix(matrix: AffineTransform) -> GeneralQuadric {
+        GeneralQuadric {
+            matrix: symmetrized(&matrix),
+        }
+    }
+
+    pub fn from_form<F: QuadraticForm>(form: &F) -> GeneralQuadric {
+        Self::from_matrix(form.quadratic_form_matrix())
+    }
+
+    pub fn coefficients(&self) -> [f64; 10] {
+        let m = &self.matrix;
+
+        [
+            m.at(0, 0),
+            m.at(1, 1),
+            m.at(2, 2),
+            2.0 * m.at(0, 1),
+            2.0 * m.at(1, 2),
+            2.0 * m.at(0, 2),
+            2.0 * m.at(0, 3),
+            2.0 * m.at(1, 3),
+            2.0 * m.at(2, 3),
+            m.at(3, 3),
+        ]
+    }
+}
+
+impl QuadraticForm for GeneralQuadric {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        self.matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        forms::{ellipsoid::Ellipsoid, sphere::Sphere},
+        math::affine::primitives::{Point, Vector},
+    };
+
+    // One equation of every type, as coefficients of Ax² + By² + Cz² + Dxy + Eyz + Fxz
+    // + Gx + Hy + Iz + J
+    const CASES: [([f64; 10], QuadricType); 20] = [
+        (
+            [1.0, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+            QuadricType::RealEllipsoid,
+        ),
+        (
+            [1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            QuadricType::ImaginaryEllipsoid,
+        ),
+        (
+            [1.0, 1.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+            QuadricType::HyperboloidOfOneSheet,
+        ),
+        (
+            [1.0, 1.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            QuadricType::HyperboloidOfTwoSheets,
+        ),
+        (
+            [1.0, 4.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            QuadricType::RealEllipticCone,
+        ),
+        (
+            [1.0, 1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            QuadricType::ImaginaryEllipticCone,
+        ),
+        (
+            [1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0, 0.0],
+            QuadricType::EllipticParaboloid,
+        ),
+        (
+            [1.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0, 0.0],
+            QuadricType::HyperbolicParaboloid,
+        ),
+        (
+            [1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+            QuadricType::RealEllipticCylinder,
+        ),
+        (
+            [1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            QuadricType::ImaginaryEllipticCylinder,
+        ),
+        (
+            [1.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+            QuadricType::HyperbolicCylinder,
+        ),
+        (
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0],
+            QuadricType::ParabolicCylinder,
+        ),
+        (
+            [1.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            QuadricType::RealIntersectingPlanes,
+        ),
+        (
+            [1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            QuadricType::ImaginaryIntersectingPlanes,
+        ),
+        (
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+            QuadricType::RealParallelPlanes,
+        ),
+        (
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            QuadricType::ImaginaryParallelPlanes,
+        ),
+        (
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            QuadricType::CoincidentPlanes,
+        ),
+        (
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0],
+            QuadricType::SinglePlane,
+        ),
+        (
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            QuadricType::EmptySet,
+        ),
+        ([0.0; 10], QuadricType::WholeSpace),
+    ];
+
+    fn classify<F: QuadraticForm>(form: &F) -> QuadricType {
+        classify_quadric(
+            &form.quadratic_form_matrix(),
+            DEFAULT_CLASSIFICATION_TOLERANCE,
+        )
+    }
+
+    // The image of the quadric under the transform, T^-T Q T^-1
+    fn moved(coefficients: [f64; 10], transform: AffineTransform) -> GeneralQuadric {
+        let matrix = GeneralQuadric::from_coefficients(coefficients).quadratic_form_matrix();
+        let inverse = transform.inverse().unwrap();
+        GeneralQuadric::from_matrix(inverse.transpose() * matrix * inverse)
+    }
+
+    #[test]
+    fn every_type_is_recognized() {
+        for (coefficients, kind) in CASES {
+            assert_eq!(
+                classify(&GeneralQuadric::from_coefficients(coefficients)),
+                kind
+            );
+
+            let negated = coefficients.map(|coefficient| -3.0 * coefficient);
+            assert_eq!(classify(&GeneralQuadric::from_coefficients(negated)), kind);
+        }
+    }
+
+    #[test]
+    fn types_are_affine_invariants() {
+        let transforms = [
+            translate(Vector::new(0.5, -2.0, 3.0)) * rotate_y(0.7) * rotate_x(-1.3),
+            rotate_z(2.1) * scale(3.0, 0.5, 1.5),
+            translate(Vector::new(1e6, 2e6, -3e6)) * rotate_x(0.4) * scale(1e6, 1e6, 1e6),
+            translate(Vector::new(1e-6, 0.0, -2e-6)) * rotate_z(-0.9) * scale(1e-6, 1e-6, 1e-6),
+        ];
+
+        for (coefficients, kind) in CASES {
+            for &transform in &transforms {
+                assert_eq!(
+                    classify(&moved(coefficients, transform)),
+                    kind,
+                    "{:?} moved by {:?}",
+                    kind,
+                    transform
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn spheres_of_any_size_are_ellipsoids() {
+        for radius in [1e-9, 1e-6, 1e-3, 1.0, 1e4, 1e5, 1e6, 1e9] {
+            assert_eq!(
+                classify(&Sphere::with_radius(radius)),
+                QuadricType::RealEllipsoid,
+                "radius {}",
+                radius
+            );
+
+            let center = Point::new(2.0 * radius, -radius, 0.5 * radius);
+            let placed = Ellipsoid::new(center, [radius; 3], rotate_x(0.3));
+            assert_eq!(classify(&placed), QuadricType::RealEllipsoid);
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use crate::{
+    forms::{implicit::*, transformed::Transformed},
+    math::affine::primitives::{Point, Vector},
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+const BISECTION_ITERATIONS: usize = 64;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Vector,
+}
+
+impl Ray {
+    pub fn new(origin: Point, direction: Vector) -> Ray {
+        Ray { origin, direction }
+    }
+
+    pub fn at(&self, t: f64) -> Point {
+        self.origin + self.direction * t
+    }
+}
+
+// The ray parameters where it enters and exits the inside of the form (the points
+// with a negative value) around the first crossing at a nonnegative parameter, which
+// is where the point and outward normal are taken. Boundaries that do not exist, as
+// for unbounded forms, are infinite.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hit {
+    pub entry: f64,
+    pub exit: f64,
+    pub point: Point,
+    pub normal: Vector,
+}
+
+impl Hit {
+    pub fn t(&self) -> f64 {
+        if self.entry >= 0.0 {
+            self.entry
+        } else {
+            self.exit
+        }
+    }
+}
+
+pub trait RayIntersection {
+    fn intersect_ray(&self, ray: &Ray) -> Option<Hit>;
+}
+
+fn hit_between<F: DifferentialForm + ?Sized>(
+    form: &F,
+    ray: &Ray,
+    entry: f64,
+    exit: f64,
+) -> Option<Hit> {
+    let t = if entry >= 0.0 { entry } else { exit };
+    if !(0.0..f64::INFINITY).contains(&t) {
+        return None;
+    }
+
+    let point = ray.at(t);
+    Some(Hit {
+        entry,
+        exit,
+        point,
+        normal: form.derivative_at(point).normalized(),
+    })
+}
+
+// Solves a t² + 2 b t + c = 0 for the value of the form along the ray
+impl<T: QuadraticForm> RayIntersection for T {
+    fn intersect_ray(&self, ray: &Ray) -> Option<Hit> {
+        let matrix = self.quadratic_form_matrix();
+        let origin = ray.origin.as_matrix();
+        let direction = ray.direction.as_matrix();
+
+        let a = (direction.transpose() * matrix * direction).num();
+        let b = 0.5 * (direction.transpose() * (matrix + matrix.transpose()) * origin).num();
+        let c = (origin.transpose() * matrix * origin).num();
+
+        if a.abs() <= f64::EPSILON * (b.abs() + c.abs()) {
+            if b == 0.0 {
+                return None;
+            }
+
+            let root = -c / (2.0 * b);
+            return if b > 0.0 {
+                hit_between(self, ray, f64::NEG_INFINITY, root)
+            } else {
+                hit_between(self, ray, root, f64::INFINITY)
+            };
+        }
+
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        // Avoids cancellation between b and the square root of the discriminant
+        let q = -(b + b.signum() * discriminant.sqrt());
+        let (first, second) = if q == 0.0 {
+            (0.0, 0.0)
+        } else {
+            let (r1, r2) = (q / a, c / q);
+            (r1.min(r2), r1.max(r2))
+        };
+
+        if a > 0.0 {
+            hit_between(self, ray, first, second)
+        } else if first >= 0.0 {
+            hit_between(self, ray, f64::NEG_INFINITY, first)
+        } else {
+            hit_between(self, ray, second, f64::INFINITY)
+        }
+    }
+}
+
+impl<F: RayIntersection> RayIntersection for Transformed<F> {
+    // Affine maps preserve ray parameters, only the normal needs to be carried back
+    fn intersect_ray(&self, ray: &Ray) -> Option<Hit> {
+        let inverse = self.inverse_transform();
+        let local = self
+            .form()
+            .intersect_ray(&Ray::new(inverse * ray.origin, inverse * ray.direction))?;
+
+        let normal = inverse.submatrix::<3, 3>(0, 0).transpose()
+            * local.normal.as_matrix().submatrix::<3, 1>(0, 0);
+
+        Some(Hit {
+            point: ray.at(local.t()),
+            normal: Vector::new(normal.at(0, 0), normal.at(1, 0), normal.at(2, 0)).normalized(),
+            ..local
+        })
+    }
+}
+
+fn refine_crossing<F: ImplicitForm + ?Sized>(
+    form: &F,
+    ray: &Ray,
+    mut low: f64,
+    mut high: f64,
+) -> f64 {
+    let low_inside = form.implicit_form_value(ray.at(low)) <= 0.0;
+
+    for _ in 0..BISECTION_ITERATIONS {
+        let middle = 0.5 * (low + high);
+        if middle <= low || middle >= high {
+            break;
+        }
+
+        if (form.implicit_form_value(ray.at(middle)) <= 0.0) == low_inside {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    0.5 * (low + high)
+}
+
+// Marches along the ray up to max_distance in steps of the given length and refines
+// sign changes by bisection, so crossings closer together than a step, and tangent
+// contacts, can be missed. Only the part of the ray ahead of the origin is searched,
+// so the entry of a ray starting inside is left infinite. There is no hit unless the
+// step is positive and both lengths are finite.
+pub fn intersect_ray_numerically<F: ImplicitForm + DifferentialForm + ?Sized>(
+    form: &F,
+    ray: &Ray,
+    max_distance: f64,
+    step: f64,
+) -> Option<Hit> {
+    if !(step > 0.0 && step.is_finite() && max_distance.is_finite()) {
+        return None;
+    }
+
+    let mut crossings = [f64::NEG_INFINITY, f64::INFINITY];
+    let mut inside = form.implicit_form_value(ray.origin) <= 0.0;
+    let mut found = if inside { 1 } else { 0 };
+    let first = found;
+
+    // Parameters are multiples of the step rather than running sums, which could stall
+    let steps = (max_distance / step).ceil() as usize;
+    let mut t = 0.0;
+    for index in 1..=steps {
+        if found == 2 {
+            break;
+        }
+
+        let next = (index as f64 * step).min(max_distance);
+        let next_inside = form.implicit_form_value(ray.at(next)) <= 0.0;
+
+        if next_inside != inside {
+            crossings[found] = refine_crossing(form, ray, t, next);
+            found += 1;
+        }
+
+        t = next;
+        inside = next_inside;
+    }
+
+    if found == first {
+        return None;
+    }
+
+    hit_between(form, ray, crossings[0], crossings[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::{quadric::GeneralQuadric, sphere::Sphere};
+
+    // The plane z = 1, with the inside below it
+    fn plane() -> GeneralQuadric {
+        GeneralQuadric::from_coefficients([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, -1.0])
+    }
+
+    fn assert_hit(hit: Option<Hit>, entry: f64, exit: f64, point: Point, normal: Vector) {
+        let hit = hit.unwrap();
+        for (actual, expected) in [(hit.entry, entry), (hit.exit, exit)] {
+            assert!(actual == expected || (actual - expected).abs() < 1e-9);
+        }
+        assert!((hit.point - point).length() < 1e-9);
+        assert!((hit.normal - normal).length() < 1e-9);
+    }
+
+    #[test]
+    fn rays_hit_spheres_analytically() {
+        let sphere = Sphere::with_radius(2.0);
+
+        let outside = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        let point = Point::new(-2.0, 0.0, 0.0);
+        assert_hit(
+            sphere.intersect_ray(&outside),
+            3.0,
+            7.0,
+            point,
+            Vector::new(-1.0, 0.0, 0.0),
+        );
+
+        // Parameters scale with the length of the direction
+        let inside = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.5, 0.0));
+        let point = Point::new(0.0, 2.0, 0.0);
+        assert_hit(
+            sphere.intersect_ray(&inside),
+            -4.0,
+            4.0,
+            point,
+            Vector::new(0.0, 1.0, 0.0),
+        );
+
+        let missing = Ray::new(Point::new(-5.0, 3.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        let behind = Ray::new(Point::new(5.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+        assert!(sphere.intersect_ray(&missing).is_none());
+        assert!(sphere.intersect_ray(&behind).is_none());
+    }
+
+    #[test]
+    fn rays_hit_planes_analytically() {
+        let plane = plane();
+
+        let up = Ray::new(Point::new(1.0, 2.0, 0.0), Vector::new(0.0, 0.0, 2.0));
+        let point = Point::new(1.0, 2.0, 1.0);
+        let normal = Vector::new(0.0, 0.0, 1.0);
+        assert_hit(
+            plane.intersect_ray(&up),
+            f64::NEG_INFINITY,
+            0.5,
+            point,
+            normal,
+        );
+
+        let down = Ray::new(Point::new(0.0, 0.0, 3.0), Vector::new(1.0, 0.0, -1.0));
+        let point = Point::new(2.0, 0.0, 1.0);
+        assert_hit(
+            plane.intersect_ray(&down),
+            2.0,
+            f64::INFINITY,
+            point,
+            normal,
+        );
+
+        let parallel = Ray::new(Point::new(0.0, 0.0, 3.0), Vector::new(1.0, 1.0, 0.0));
+        let away = Ray::new(Point::new(0.0, 0.0, 3.0), Vector::new(0.0, 0.0, 1.0));
+        assert!(plane.intersect_ray(&parallel).is_none());
+        assert!(plane.intersect_ray(&away).is_none());
+    }
+
+    #[test]
+    fn marching_finds_the_analytic_hits() {
+        let sphere = Sphere::with_radius(2.0);
+        let plane = plane();
+        let rays = [
+            Ray::new(Point::new(-5.0, 0.3, 0.0), Vector::new(1.0, 0.0, 0.0)),
+            Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.5, 0.5)),
+            Ray::new(Point::new(0.0, 0.0, 3.0), Vector::new(1.0, 0.0, -1.0)),
+            Ray::new(Point::new(-5.0, 3.0, 0.0), Vector::new(1.0, 0.0, 0.0)),
+        ];
+
+        for ray in rays {
+            for (numeric, analytic) in [
+                (
+                    intersect_ray_numerically(&sphere, &ray, 20.0, 0.1),
+                    sphere.intersect_ray(&ray),
+                ),
+                (
+                    intersect_ray_numerically(&plane, &ray, 20.0, 0.1),
+                    plane.intersect_ray(&ray),
+                ),
+            ] {
+                let Some(analytic) = analytic else {
+                    assert!(numeric.is_none());
+                    continue;
+                };
+                // The march does not look behind the origin
+                let entry = if analytic.entry < 0.0 {
+                    f64::NEG_INFINITY
+                } else {
+                    analytic.entry
+                };
+                assert_hit(
+                    numeric,
+                    entry,
+                    analytic.exit,
+                    analytic.point,
+                    analytic.normal,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn marching_rejects_invalid_steps() {
+        let sphere = Sphere::with_radius(2.0);
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+
+        for step in [0.0, -0.1, f64::NAN, f64::INFINITY] {
+            assert!(intersect_ray_numerically(&sphere, &ray, 20.0, step).is_none());
+        }
+        for max_distance in [f64::NAN, f64::INFINITY, -1.0] {
+            assert!(intersect_ray_numerically(&sphere, &ray, max_distance, 0.1).is_none());
+        }
+    }
+}
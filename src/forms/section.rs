@@ -0,0 +1,260 @@
+use crate::{
+    conics::{
+        canonical::{reduce_conic, CanonicalConic},
+        conic::GeneralConic,
+    },
+    error::{Error, Result},
+    forms::implicit::*,
+    math::{
+        affine::primitives::{Point, Vector},
+        affine2d::primitives::{Point2, Vector2},
+        matrix::Matrix,
+    },
+};
+
+// Plane through a given point, its origin, spanned by two orthonormal axes u and v,
+// where the plane coordinates (s, t) are the point origin + s u + t v
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SectionPlane {
+    origin: Point,
+    axes: [Vector; 2],
+}
+
+impl SectionPlane {
+    pub fn new(origin: Point, normal: Vector) -> SectionPlane {
+        Self::try_new(origin, normal).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    // The first axis is orthogonal to the coordinate axis least aligned with the normal,
+    // and the axes are oriented so that u × v is the normal
+    pub fn try_new(origin: Point, normal: Vector) -> Result<SectionPlane> {
+        let length = normal.length();
+        if length == 0.0 || !length.is_finite() {
+            return Err(Error::ZeroVector);
+        }
+
+        let normal = normal * (1.0 / length);
+        let least_aligned = (0..3)
+            .min_by(|&i, &j| normal.at(i).abs().total_cmp(&normal.at(j).abs()))
+            .unwrap_or(0);
+        let mut coordinate_axis = Vector::new(0.0, 0.0, 0.0);
+        *coordinate_axis.at_mut(least_aligned) = 1.0;
+
+        let u = coordinate_axis.cross(normal).normalized();
+        Ok(SectionPlane {
+            origin,
+            axes: [u, normal.cross(u)],
+        })
+    }
+
+    // Orthonormalizes the axes, keeping the direction of the first one
+    pub fn from_axes(origin: Point, u: Vector, v: Vector) -> Result<SectionPlane> {
+        if u.length() == 0.0 {
+            return Err(Error::ZeroVector);
+        }
+
+        let u = u.normalized();
+        let v_orthogonal = v - u * (u * v);
+        if v_orthogonal.length() <= f64::EPSILON * v.length() {
+            return Err(Error::ZeroVector);
+        }
+
+        Ok(SectionPlane {
+            origin,
+            axes: [u, v_orthogonal.normalized()],
+        })
+    }
+
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    pub fn axes(&self) -> [Vector; 2] {
+        self.axes
+    }
+
+    pub fn normal(&self) -> Vector {
+        self.axes[0].cross(self.axes[1])
+    }
+
+    pub fn point_at(&self, coordinates: Point2) -> Point {
+        self.origin + self.axes[0] * coordinates.at(0) + self.axes[1] * coordinates.at(1)
+    }
+
+    pub fn vector_at(&self, coordinates: Vector2) -> Vector {
+        self.axes[0] * coordinates.at(0) + self.axes[1] * coordinates.at(1)
+    }
+
+    // Plane coordinates of the orthogonal projection of the point
+    pub fn coordinates(&self, point: Point) -> Point2 {
+        let offset = point - self.origin;
+        Point2::new(self.axes[0] * offset, self.axes[1] * offset)
+    }
+
+    // Maps homogeneous plane coordinates (s, t, 1) to homogeneous space coordinates
+    pub fn embedding(&self) -> Matrix<f64, 4, 3> {
+        let mut embedding = Matrix::zero();
+
+        for row in 0..3 {
+            *embedding.at_mut(row, 0) = self.axes[0].at(row);
+            *embedding.at_mut(row, 1) = self.axes[1].at(row);
+            *embedding.at_mut(row, 2) = self.origin.at(row);
+        }
+        *embedding.at_mut(3, 2) = 1.0;
+
+        embedding
+    }
+}
+
+// The conic cut from a quadric by a plane, in the coordinates of the plane
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlaneSection {
+    plane: SectionPlane,
+    conic: GeneralConic,
+    canonical: CanonicalConic,
+}
+
+impl PlaneSection {
+    pub fn plane(&self) -> SectionPlane {
+        self.plane
+    }
+
+    pub fn conic(&self) -> GeneralConic {
+        self.conic
+    }
+
+    pub fn canonical_form(&self) -> CanonicalConic {
+        self.canonical
+    }
+
+    pub fn center(&self) -> Point {
+        self.plane.point_at(self.canonical.center())
+    }
+
+    pub fn axes(&self) -> [Vector; 2] {
+        self.canonical.axes().map(|axis| self.plane.vector_at(axis))
+    }
+
+    pub fn point_at(&self, branch: usize, t: f64) -> Option<Point> {
+        self.canonical
+            .point_at(branch, t)
+            .map(|point| self.plane.point_at(point))
+    }
+}
+
+// Restricts the quadratic form to the plane, E^T Q E with E the embedding of the plane
+pub fn plane_section<F: QuadraticForm + ?Sized>(
+    form: &F,
+    plane: SectionPlane,
+    tolerance: f64,
+) -> PlaneSection {
+    let embedding = plane.embedding();
+    let matrix = embedding.transpose() * form.quadratic_form_matrix() * embedding;
+
+    PlaneSection {
+        plane,
+        conic: GeneralConic::from_matrix(matrix),
+        canonical: reduce_conic(&matrix, tolerance),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conics::conic::ConicType,
+        forms::{cone::EllipticCone, quadric::DEFAULT_CLASSIFICATION_TOLERANCE, sphere::Sphere},
+    };
+
+    fn section<F: QuadraticForm>(form: &F, origin: Point, normal: Vector) -> PlaneSection {
+        plane_section(
+            form,
+            SectionPlane::new(origin, normal),
+            DEFAULT_CLASSIFICATION_TOLERANCE,
+        )
+    }
+
+    #[test]
+    fn planes_keep_their_origin() {
+        let plane = SectionPlane::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 0.0, 2.0));
+        let [u, v] = plane.axes();
+
+        assert_eq!(
+            (plane.point_at(Point2::new(0.0, 0.0)) - plane.origin()).length(),
+            0.0
+        );
+        assert!((u.cross(v) - Vector::new(0.0, 0.0, 1.0)).length() < 1e-15);
+        let point = plane.point_at(Point2::new(0.5, -2.0));
+        assert!((plane.coordinates(point) - Point2::new(0.5, -2.0)).length() < 1e-12);
+        assert!((point.at(2) - 3.0).abs() < 1e-12);
+        assert!(
+            SectionPlane::try_new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0)).is_err()
+        );
+    }
+
+    #[test]
+    fn spheres_give_circles() {
+        let sphere = Sphere::with_radius(2.0);
+        let section = section(
+            &sphere,
+            Point::new(0.0, 0.0, 1.0),
+            Vector::new(0.0, 0.0, 1.0),
+        );
+        let canonical = section.canonical_form();
+
+        assert_eq!(canonical.kind(), ConicType::RealEllipse);
+        for semi_axis in canonical.semi_axes() {
+            assert!((semi_axis.unwrap() - 3.0_f64.sqrt()).abs() < 1e-12);
+        }
+        assert!((section.center() - Point::new(0.0, 0.0, 1.0)).length() < 1e-12);
+        for t in [0.0, 1.0, 2.5] {
+            let point = section.point_at(0, t).unwrap();
+            assert!(sphere.implicit_form_value(point).abs() < 1e-12);
+            assert!((point.at(2) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn cones_give_every_conic() {
+        let cone = EllipticCone::with_radii(1.0, 1.0);
+        let cases = [
+            (
+                Point::new(0.0, 0.0, 2.0),
+                Vector::new(0.0, 0.0, 1.0),
+                ConicType::RealEllipse,
+            ),
+            (
+                Point::new(1.0, 0.0, 0.0),
+                Vector::new(1.0, 0.0, 0.0),
+                ConicType::Hyperbola,
+            ),
+            (
+                Point::new(0.0, 1.0, 0.0),
+                Vector::new(0.0, 1.0, -1.0),
+                ConicType::Parabola,
+            ),
+            (
+                Point::new(0.0, 0.0, 0.0),
+                Vector::new(1.0, 0.0, 0.0),
+                ConicType::RealIntersectingLines,
+            ),
+            (
+                Point::new(0.0, 0.0, 0.0),
+                Vector::new(0.0, 0.0, 1.0),
+                ConicType::ImaginaryIntersectingLines,
+            ),
+        ];
+
+        for (origin, normal, kind) in cases {
+            let section = section(&cone, origin, normal);
+            assert_eq!(section.canonical_form().kind(), kind);
+
+            for branch in 0..section.canonical_form().branches() {
+                let point = section.point_at(branch, 0.7).unwrap();
+                assert!(cone.implicit_form_value(point).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    forms::{implicit::*, parametric::*},
+    math::affine::{
+        primitives::{Point, Vector},
+        transforms::*,
+    },
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+    curvature: f64,
+}
+
+impl Sphere {
+    pub fn with_curvature(curvature: f64) -> Sphere {
+        Sphere { curvature }
+    }
+
+    pub fn with_radius(radius: f64) -> Sphere {
+        Self::with_curvature(1.0 / (radius * radius))
+    }
+
+    pub fn radius(&self) -> f64 {
+        1.0 / self.curvature.sqrt()
+    }
+}
+
+impl QuadraticForm for Sphere {
+    fn quadratic_form_matrix(&self) -> AffineTransform {
+        AffineTransform::diagonal(&[self.curvature, self.curvature, self.curvature, -1.0])
+    }
+}
+
+// Longitude u and latitude v
+impl ParametricForm for Sphere {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::spherical()
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let radius = self.radius();
+        Point::new(
+            radius * v.cos() * u.cos(),
+            radius * v.cos() * u.sin(),
+            radius * v.sin(),
+        )
+    }
+
+    fn partial_u(&self, u: f64, v: f64) -> Vector {
+        let radius = self.radius();
+        Vector::new(-radius * v.cos() * u.sin(), radius * v.cos() * u.cos(), 0.0)
+    }
+
+    fn partial_v(&self, u: f64, v: f64) -> Vector {
+        let radius = self.radius();
+        Vector::new(
+            -radius * v.sin() * u.cos(),
+            -radius * v.sin() * u.sin(),
+            radius * v.cos(),
+        )
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    forms::{implicit::*, parametric::*},
+    math::affine::primitives::{Point, Vector},
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// (|x/a|^p + |y/b|^p)^(q/p) + |z/c|^q = 1, where p controls the squareness of the
+// horizontal sections and q the squareness along z. Exponents of 2 give an ellipsoid,
+// larger ones tend to a box and 1 gives an octahedron.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Superellipsoid {
+    radii: [f64; 3],
+    horizontal_exponent: f64,
+    vertical_exponent: f64,
+}
+
+impl Superellipsoid {
+    pub fn new(
+        rx: f64,
+        ry: f64,
+        rz: f64,
+        horizontal_exponent: f64,
+        vertical_exponent: f64,
+    ) -> Superellipsoid {
+        Superellipsoid {
+            radii: [rx, ry, rz],
+            horizontal_exponent,
+            vertical_exponent,
+        }
+    }
+
+    pub fn radii(&self) -> [f64; 3] {
+        self.radii
+    }
+
+    pub fn exponents(&self) -> (f64, f64) {
+        (self.horizontal_exponent, self.vertical_exponent)
+    }
+
+    fn scaled(&self, u: Point) -> [f64; 3] {
+        core::array::from_fn(|i| (u.at(i) / self.radii[i]).abs())
+    }
+}
+
+impl ImplicitForm for Superellipsoid {
+    fn implicit_form_value(&self, u: Point) -> f64 {
+        let [x, y, z] = self.scaled(u);
+        let (p, q) = (self.horizontal_exponent, self.vertical_exponent);
+
+        (x.powf(p) + y.powf(p)).powf(q / p) + z.powf(q) - 1.0
+    }
+}
+
+impl DifferentialForm for Superellipsoid {
+    fn derivative_at(&self, u: Point) -> Vector {
+        let [x, y, z] = self.scaled(u);
+        let (p, q) = (self.horizontal_exponent, self.vertical_exponent);
+        let sum = x.powf(p) + y.powf(p);
+
+        // The horizontal terms vanish on the z axis, where the power of the sum may
+        // not be finite
+        let horizontal = if sum > 0.0 {
+            q * sum.powf(q / p - 1.0)
+        } else {
+            0.0
+        };
+        let partial = |scaled: f64, exponent: f64, factor: f64, i: usize| {
+            factor * scaled.powf(exponent - 1.0) * u.at(i).signum() / self.radii[i]
+        };
+
+        Vector::new(
+            partial(x, p, horizontal, 0),
+            partial(y, p, horizontal, 1),
+            partial(z, q, q, 2),
+        )
+    }
+}
+
+fn signed_power(value: f64, exponent: f64) -> f64 {
+    value.signum() * value.abs().powf(exponent)
+}
+
+// Derivative of signed_power(f(t), exponent) given f(t) and f'(t), which is infinite
+// at the edges where the exponent is below one
+fn signed_power_derivative(value: f64, derivative: f64, exponent: f64) -> f64 {
+    exponent * value.abs().powf(exponent - 1.0) * derivative
+}
+
+// Longitude u and latitude v through signed powers of the sphere parametrization
+impl ParametricForm for Superellipsoid {
+    fn domain(&self) -> ParameterDomain {
+        ParameterDomain::spherical()
+    }
+
+    fn point_at(&self, u: f64, v: f64) -> Point {
+        let (horizontal, vertical) = (2.0 / self.horizontal_exponent, 2.0 / self.vertical_exponent);
+        let ring = signed_power(v.cos(), vertical);
+
+        Point::new(
+            self.radii[0] * ring * signed_power(u.cos(), horizontal),
+            self.radii[1] * ring * signed_power(u.sin(), horizontal),
+            self.radii[2] * signed_power(v.sin(), vertical),
+        )
+    }
+
+    fn partial_u(&self, u: f64, v: f64) -> Vector {
+        let (horizontal, vertical) = (2.0 / self.horizontal_exponent, 2.0 / self.vertical_exponent);
+        let ring = signed_power(v.cos(), vertical);
+
+        Vector::new(
+            self.radii[0] * ring * signed_power_derivative(u.cos(), -u.sin(), horizontal),
+            self.radii[1] * ring * signed_power_derivative(u.sin(), u.cos(), horizontal),
+            0.0,
+        )
+    }
+
+    fn partial_v(&self, u: f64, v: f64) -> Vector {
+        let (horizontal, vertical) = (2.0 / self.horizontal_exponent, 2.0 / self.vertical_exponent);
+        let ring = signed_power_derivative(v.cos(), -v.sin(), vertical);
+
+        Vector::new(
+            self.radii[0] * ring * signed_power(u.cos(), horizontal),
+            self.radii[1] * ring * signed_power(u.sin(), horizontal),
+            self.radii[2] * signed_power_derivative(v.sin(), v.cos(), vertical),
+        )
+    }
+}
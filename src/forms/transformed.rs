@@ -0,0 +1,118 @@
+use crate::{
+    error::{Error, Result},
+    forms::{implicit::*, quadric::GeneralQuadric},
+    math::{
+        affine::{
+            primitives::{Point, Vector},
+            transforms::*,
+        },
+        matrix::Matrix,
+    },
+};
+
+// A form placed in world coordinates by a transform mapping form coordinates to world
+// coordinates, evaluated by pulling points back through the inverse transform
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transformed<F> {
+    form: F,
+    transform: AffineTransform,
+    inverse: AffineTransform,
+}
+
+impl<F> Transformed<F> {
+    // The transform must have [0 0 0 1] as its last row and be invertible
+    pub fn new(form: F, transform: AffineTransform) -> Result<Transformed<F>> {
+        if (0..4).any(|col| transform.at(3, col) != if col == 3 { 1.0 } else { 0.0 }) {
+            return Err(Error::NotAffine);
+        }
+
+        let inverse = transform.try_inverse()?;
+        Ok(Transformed {
+            form,
+            transform,
+            inverse,
+        })
+    }
+
+    pub fn form(&self) -> &F {
+        &self.form
+    }
+
+    pub fn transform(&self) -> AffineTransform {
+        self.transform
+    }
+
+    pub fn inverse_transform(&self) -> AffineTransform {
+        self.inverse
+    }
+
+    // Applies another transform after the current one
+    pub fn then(self, transform: AffineTransform) -> Result<Transformed<F>> {
+        Self::new(self.form, transform * self.transform)
+    }
+}
+
+impl<F: QuadraticForm> Transformed<F> {
+    // The matrix of the quadric in world coordinates, by congruence with the inverse
+    pub fn quadric(&self) -> GeneralQuadric {
+        GeneralQuadric::from_matrix(
+            self.inverse.transpose() * self.form.quadratic_form_matrix() * self.inverse,
+        )
+    }
+}
+
+impl<F: ImplicitForm> ImplicitForm for Transformed<F> {
+    fn implicit_form_value(&self, u: Point) -> f64 {
+        self.form.implicit_form_value(self.inverse * u)
+    }
+}
+
+impl<F: DifferentialForm> DifferentialForm for Transformed<F> {
+    // Gradients are covectors, so they transform with the inverse transpose of the
+    // linear part
+    fn derivative_at(&self, u: Point) -> Vector {
+        let gradient = self.form.derivative_at(self.inverse * u);
+        let world = self.inverse.submatrix::<3, 3>(0, 0).transpose()
+            * gradient.as_matrix().submatrix::<3, 1>(0, 0);
+
+        Vector::new(world.at(0, 0), world.at(1, 0), world.at(2, 0))
+    }
+}
+
+impl<F: SecondDifferentialForm> SecondDifferentialForm for Transformed<F> {
+    fn hessian_at(&self, u: Point) -> Matrix<f64, 3, 3> {
+        let linear = self.inverse.submatrix::<3, 3>(0, 0);
+        linear.transpose() * self.form.hessian_at(self.inverse * u) * linear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::sphere::Sphere;
+
+    #[test]
+    fn rejects_transforms_that_are_not_affine() {
+        let mut projective = AffineTransform::identity();
+        *projective.at_mut(3, 2) = 0.5;
+
+        let error = Transformed::new(Sphere::with_radius(1.0), projective).unwrap_err();
+        assert_eq!(error, Error::NotAffine);
+    }
+
+    #[test]
+    fn rejects_singular_transforms() {
+        let error = Transformed::new(Sphere::with_radius(1.0), scale(1.0, 0.0, 1.0)).unwrap_err();
+        assert_eq!(error, Error::SingularMatrix);
+    }
+
+    #[test]
+    fn evaluates_through_the_inverse() {
+        let sphere = Transformed::new(Sphere::with_radius(1.0), scale(7.0, 7.0, 1.0)).unwrap();
+
+        assert_eq!(sphere.inverse_transform().at(3, 3), 1.0);
+        assert!(sphere.implicit_form_value(Point::new(7.0, 0.0, 0.0)).abs() < 1e-12);
+        assert!(sphere.implicit_form_value(Point::new(0.0, 0.0, 1.0)).abs() < 1e-12);
+    }
+}
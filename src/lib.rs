@@ -1,4 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("proforma requires either the `std` or the `libm` feature for floating point math");
+
+extern crate alloc;
+
+pub mod conics;
+pub mod error;
 pub mod forms;
 pub mod math;
+pub mod mesh;
 pub mod primitives;
+#[cfg(feature = "render")]
 pub mod window;
+
+pub use error::{Error, Result};
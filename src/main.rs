@@ -1,14 +1,29 @@
 use proforma::{
-    forms::{ellipsoid::Ellipsoid, implicit::QuadraticForm},
+    forms::{
+        cone::EllipticCone,
+        cylinder::{EllipticCylinder, HyperbolicCylinder, ParabolicCylinder},
+        ellipsoid::Ellipsoid,
+        hyperboloid::{HyperboloidOfOneSheet, HyperboloidOfTwoSheets},
+        implicit::QuadraticForm,
+        paraboloid::{EllipticParaboloid, HyperbolicParaboloid},
+        planes::{IntersectingPlanes, ParallelPlanes},
+        quadric::DEFAULT_CLASSIFICATION_TOLERANCE,
+        section::{plane_section, SectionPlane},
+        sphere::Sphere,
+        superellipsoid::Superellipsoid,
+        transformed::Transformed,
+    },
     math::{
         affine::{
             self,
             primitives::{Point, Vector},
+            transforms::AffineTransform,
         },
         matrix::Matrix,
     },
     primitives::color::Color,
     window::Window,
+    Error, Result,
 };
 
 use std::time::Instant;
@@ -27,14 +42,42 @@ const CLEAR_COLOR: Color = Color {
     a: 1.0,
 };
 
+const FORM_NAMES: [&str; 13] = [
+    "Ellipsoid",
+    "Sphere",
+    "Hyperboloid of one sheet",
+    "Hyperboloid of two sheets",
+    "Elliptic paraboloid",
+    "Hyperbolic paraboloid",
+    "Elliptic cone",
+    "Elliptic cylinder",
+    "Hyperbolic cylinder",
+    "Parabolic cylinder",
+    "Intersecting planes",
+    "Parallel planes",
+    "Superellipsoid",
+];
+
+const SUPERELLIPSOID_FORM: usize = 12;
+
+const SHADING_NAMES: [&str; 3] = ["Lighting", "Gaussian curvature", "Mean curvature"];
+
 #[derive(Debug)]
 struct State {
+    pub form: usize,
     pub rx: f64,
     pub ry: f64,
     pub rz: f64,
+    pub horizontal_exponent: f64,
+    pub vertical_exponent: f64,
     pub divs: i32,
     pub max_divs: i32,
     pub light_intensity: f32,
+    pub shading: usize,
+    pub curvature_range: f32,
+    pub show_section: bool,
+    pub section_height: f64,
+    pub section_tilt: f64,
     pub left_mouse_button_down: bool,
     pub right_mouse_button_down: bool,
     pub current_mouse_position: Option<glutin::dpi::PhysicalPosition<f64>>,
@@ -77,49 +120,191 @@ uniform mat4 qf;
 uniform vec2 resolution;
 uniform float light_intensity;
 uniform int divs;
+uniform int shading;
+uniform float curvature_range;
+uniform float world_scale;
+uniform int form_kind;
+uniform mat4 view_to_form;
+uniform vec3 radii;
+uniform vec2 exponents;
+uniform vec2 march_range;
+uniform int show_section;
+uniform vec4 section_plane;
 
 const float near_plane = 0.001;
 const vec4 outside_color = vec4(1.0, 1.0, 0.0, 1.0);
 const vec4 inside_color = vec4(0.7, 0.7, 0.0, 1.0);
 const vec4 void_color = vec4(0.5, 0.5, 0.5, 1.0);
+const vec4 section_color = vec4(0.9, 0.1, 0.1, 1.0);
+const float section_width = 0.002;
 const float g_scale = 1000.0;
+const int march_steps = 128;
+const int refine_steps = 24;
+const float gradient_step = 1e-4;
+
+// The section plane is normalized to give view distances
+bool on_section(vec3 point) {
+    return show_section == 1 && abs(dot(section_plane, vec4(point, 1.0))) < section_width;
+}
+
+float superellipsoid_value(vec3 view_point) {
+    vec3 q = abs((view_to_form * vec4(view_point, 1.0)).xyz / radii);
+    float horizontal = pow(q.x, exponents.x) + pow(q.y, exponents.x);
+    return pow(horizontal, exponents.y / exponents.x) + pow(q.z, exponents.y) - 1.0;
+}
+
+// Marches along the view ray through the depth range of the bounding box and refines
+// the first sign change by bisection
+void shade_superellipsoid(vec2 coord) {
+    float start = max(march_range.x, near_plane);
+    float step_size = (march_range.y - start) / march_steps;
+
+    if(step_size <= 0.0) {
+        frag_color = void_color;
+        return;
+    }
+
+    float low = start;
+    float previous = superellipsoid_value(vec3(coord, start));
+    bool found = false;
+
+    for(int i = 1; i <= march_steps; i++) {
+        float high = start + i * step_size;
+
+        if(sign(superellipsoid_value(vec3(coord, high))) != sign(previous)) {
+            for(int j = 0; j < refine_steps; j++) {
+                float middle = 0.5 * (low + high);
+                if(sign(superellipsoid_value(vec3(coord, middle))) == sign(previous)) low = middle;
+                else high = middle;
+            }
+
+            found = true;
+            break;
+        }
+
+        low = high;
+    }
+
+    if(!found) {
+        frag_color = void_color;
+        return;
+    }
+
+    vec3 point = vec3(coord, low);
+    if(on_section(point)) {
+        frag_color = section_color;
+        return;
+    }
+
+    vec3 gradient = vec3(
+        superellipsoid_value(point + vec3(gradient_step, 0.0, 0.0))
+            - superellipsoid_value(point - vec3(gradient_step, 0.0, 0.0)),
+        superellipsoid_value(point + vec3(0.0, gradient_step, 0.0))
+            - superellipsoid_value(point - vec3(0.0, gradient_step, 0.0)),
+        superellipsoid_value(point + vec3(0.0, 0.0, gradient_step))
+            - superellipsoid_value(point - vec3(0.0, 0.0, gradient_step))
+    );
+    float p = dot(normalize(gradient), normalize(-point));
+
+    if(p > 0) frag_color = outside_color * pow(p, light_intensity);
+    else frag_color = inside_color;
+}
 
 void main() {
     vec2 coord = vec2(vert.x * resolution.x, vert.y * resolution.y);
     coord = round(coord / divs) * divs / g_scale;
+
+    if(form_kind == 1) {
+        shade_superellipsoid(coord);
+        return;
+    }
+
     float free_term = dot(coord.x * qf[0].xyw + coord.y * qf[1].xyw + qf[3].xyw, vec3(coord.xy, 1));
     float line_term = dot(qf[2].xyw + vec3(qf[0].z, qf[1].z, qf[3].z), vec3(coord.xy, 1));
     float quad_term = qf[2].z;
 
-    float delta = line_term * line_term - 4 * free_term * quad_term;
+    float s1;
+    float s2;
 
-    if(delta >= 0.0) {
-        float sqrt_delta = sqrt(delta);
-        float s1 = (-line_term - sqrt_delta) / (2 * quad_term);
-        float s2 = (-line_term + sqrt_delta) / (2 * quad_term);
-
-        mat4 qf_transposium = transpose(qf + transpose(qf));
-
-        if(s1 > near_plane && s2 > near_plane) {
-            vec3 to_observer = normalize(-vec3(coord.x, coord.y, s1));
-            vec4 derivative_s1 = qf_transposium * vec4(coord.x, coord.y, s1, 1.0);
-            vec3 normal_s1 = normalize(derivative_s1.xyz);
-            float p = dot(normal_s1, to_observer);
-            float light;
-            if(p > 0) light = pow(p, light_intensity);
-            else light = 0;
-            frag_color = outside_color * light;
-        }
-        else if (s1 > near_plane || s2 > near_plane) {
-            frag_color = inside_color;
+    if(abs(quad_term) < 1e-6) {
+        if(abs(line_term) < 1e-6) {
+            frag_color = void_color;
+            return;
         }
-        else {
+
+        s1 = -free_term / line_term;
+        s2 = s1;
+    }
+    else {
+        float delta = line_term * line_term - 4 * free_term * quad_term;
+
+        if(delta < 0.0) {
             frag_color = void_color;
+            return;
         }
+
+        float sqrt_delta = sqrt(delta);
+        float r1 = (-line_term - sqrt_delta) / (2 * quad_term);
+        float r2 = (-line_term + sqrt_delta) / (2 * quad_term);
+        s1 = min(r1, r2);
+        s2 = max(r1, r2);
     }
+
+    float s;
+    if(s1 > near_plane) s = s1;
+    else if(s2 > near_plane) s = s2;
     else {
         frag_color = void_color;
+        return;
+    }
+
+    if(on_section(vec3(coord, s))) {
+        frag_color = section_color;
+        return;
+    }
+
+    mat4 qf_transposium = transpose(qf + transpose(qf));
+    vec3 to_observer = normalize(-vec3(coord.x, coord.y, s));
+    vec4 derivative = qf_transposium * vec4(coord.x, coord.y, s, 1.0);
+    vec3 normal = normalize(derivative.xyz);
+    float p = dot(normal, to_observer);
+
+    if(p <= 0) {
+        frag_color = inside_color;
+        return;
+    }
+
+    float lighting = pow(p, light_intensity);
+    if(shading == 0) {
+        frag_color = outside_color * lighting;
+        return;
+    }
+
+    // Curvature formulas of Goldman, scaled back from view to world units
+    vec3 gradient = derivative.xyz;
+    mat3 hessian = mat3(qf_transposium);
+    float gradient_length = length(gradient);
+    float curvature;
+
+    if(shading == 1) {
+        mat4 bordered = mat4(
+            vec4(hessian[0], gradient.x),
+            vec4(hessian[1], gradient.y),
+            vec4(hessian[2], gradient.z),
+            vec4(gradient, 0.0)
+        );
+        curvature = -determinant(bordered) / pow(gradient_length, 4.0) * world_scale * world_scale;
+    }
+    else {
+        float trace = hessian[0][0] + hessian[1][1] + hessian[2][2];
+        float normal_term = dot(gradient, hessian * gradient);
+        curvature = (gradient_length * gradient_length * trace - normal_term)
+            / (2.0 * pow(gradient_length, 3.0)) * world_scale;
     }
+
+    float t = clamp(curvature / curvature_range, -1.0, 1.0);
+    vec4 color = t >= 0.0 ? vec4(1.0, 1.0 - t, 1.0 - t, 1.0) : vec4(1.0 + t, 1.0 + t, 1.0, 1.0);
+    frag_color = color * lighting;
 }
 "#;
 
@@ -128,18 +313,47 @@ fn build_ui(ui: &mut imgui::Ui, state: &mut State) {
         .size([500.0, 300.0], imgui::Condition::FirstUseEver)
         .position([0.0, 0.0], imgui::Condition::FirstUseEver)
         .build(|| {
-            ui.text("Ellipsoid control");
+            ui.text("Form control");
+            ui.combo_simple_string("Form", &mut state.form, &FORM_NAMES);
             ui.slider("r_x", 0.01, 5.0, &mut state.rx);
             ui.slider("r_y", 0.01, 5.0, &mut state.ry);
             ui.slider("r_z", 0.01, 5.0, &mut state.rz);
+            if state.form == SUPERELLIPSOID_FORM {
+                ui.slider(
+                    "Horizontal exponent",
+                    0.2,
+                    10.0,
+                    &mut state.horizontal_exponent,
+                );
+                ui.slider("Vertical exponent", 0.2, 10.0, &mut state.vertical_exponent);
+            }
 
             ui.separator();
             ui.text("Render control");
             ui.slider("Max render division", 1, 64, &mut state.max_divs);
             ui.slider("Light intensity", 0.0, 100.0, &mut state.light_intensity);
+            ui.combo_simple_string("Shading", &mut state.shading, &SHADING_NAMES);
+            ui.slider("Curvature range", 0.01, 10.0, &mut state.curvature_range);
+
+            ui.separator();
+            ui.text("Cross section");
+            ui.checkbox("Show cross section", &mut state.show_section);
+            ui.slider("Section height", -5.0, 5.0, &mut state.section_height);
+            ui.slider("Section tilt", -90.0, 90.0, &mut state.section_tilt);
 
             ui.separator();
             ui.text("Info");
+            if state.form == SUPERELLIPSOID_FORM {
+                ui.text("Quadric type: none, the form is not quadratic");
+            } else {
+                ui.text(format!(
+                    "Quadric type: {:?}",
+                    selected_form(state).classify()
+                ));
+            }
+            if state.show_section && state.form != SUPERELLIPSOID_FORM {
+                section_info(ui, state);
+            }
             ui.text(format!(
                 "Camera position (x, y, z): {:.4}, {:.4}, {:.4}",
                 state.camera_position.at(0),
@@ -162,72 +376,228 @@ fn build_ui(ui: &mut imgui::Ui, state: &mut State) {
         });
 }
 
-fn main() {
-    let (mut window, event_loop) = Window::new(WINDOW_TITLE, WINDOW_WIDTH, WINDOW_HEIGHT);
-    let mut last_frame = Instant::now();
+// Horizontal plane at the height, tilted around the x axis by the angle in degrees
+fn section_plane(state: &State) -> SectionPlane {
+    let normal =
+        affine::transforms::rotate_x(state.section_tilt.to_radians()) * Vector::new(0.0, 0.0, 1.0);
+    SectionPlane::new(Point::new(0.0, 0.0, state.section_height), normal)
+}
 
-    let mut app_state = State {
-        rx: 0.5,
-        ry: 0.5,
-        rz: 0.5,
-        divs: 1,
-        max_divs: 8,
-        light_intensity: 0.5,
-        left_mouse_button_down: false,
-        right_mouse_button_down: false,
-        current_mouse_position: None,
-        previous_mouse_position: None,
-        scroll_delta: 0.0,
-        resolution: glutin::dpi::PhysicalSize::new(WINDOW_WIDTH, WINDOW_HEIGHT),
-        camera_position: Point::new(0.0, 0.0, 1.0),
-        camera_basis: Matrix::from_data([
-            [-1.0, 0.0, 0.0, 0.0],
-            [0.0, 1.0, 0.0, 0.0],
-            [0.0, 0.0, -1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0],
-        ]),
-        scale: 1.0,
-    };
+fn section_info(ui: &imgui::Ui, state: &State) {
+    let section = plane_section(
+        selected_form(state).as_ref(),
+        section_plane(state),
+        DEFAULT_CLASSIFICATION_TOLERANCE,
+    );
+    let conic = section.canonical_form();
+    let center = section.center();
+
+    ui.text(format!("Section type: {:?}", conic.kind()));
+    ui.text(format!(
+        "Section center (x, y, z): {:.4}, {:.4}, {:.4}",
+        center.at(0),
+        center.at(1),
+        center.at(2)
+    ));
+    if let [Some(a), Some(b)] = conic.semi_axes() {
+        ui.text(format!("Section semi-axes: {:.4}, {:.4}", a, b));
+    }
+    if let Some(focal_length) = conic.focal_length() {
+        ui.text(format!("Section focal length: {:.4}", focal_length));
+    }
+}
 
-    let mut shaders = [
-        (glow::VERTEX_SHADER, VERTEX_SHADER_SOURCE, 0),
-        (glow::FRAGMENT_SHADER, FRAGMENT_SHADER_SOURCE, 0),
+// The world plane (n, -n·p) pulled back to view coordinates and normalized
+fn view_section_plane(plane: &SectionPlane, inverse_view_transform: &AffineTransform) -> [f32; 4] {
+    let normal = plane.normal();
+    let world = [
+        normal.at(0),
+        normal.at(1),
+        normal.at(2),
+        -(normal * Vector::to_point(plane.origin())),
     ];
+    let view: [f64; 4] = core::array::from_fn(|col| {
+        (0..4)
+            .map(|row| world[row] * inverse_view_transform.at(row, col))
+            .sum()
+    });
+    let length = view[..3]
+        .iter()
+        .map(|value| value * value)
+        .sum::<f64>()
+        .sqrt();
 
-    let gl = window.gl();
+    view.map(|value| (value / length) as f32)
+}
 
-    let vertex_array = unsafe { gl.create_vertex_array() }.unwrap();
-    let program = unsafe { gl.create_program() }.unwrap();
+fn selected_form(state: &State) -> Box<dyn QuadraticForm> {
+    let (rx, ry, rz) = (state.rx, state.ry, state.rz);
+
+    match state.form {
+        1 => Box::new(Sphere::with_radius(rx)),
+        2 => Box::new(HyperboloidOfOneSheet::with_radii(rx, ry, rz)),
+        3 => Box::new(HyperboloidOfTwoSheets::with_radii(rx, ry, rz)),
+        4 => Box::new(EllipticParaboloid::with_radii(rx, ry)),
+        5 => Box::new(HyperbolicParaboloid::with_radii(rx, ry)),
+        6 => Box::new(EllipticCone::with_radii(rx, ry)),
+        7 => Box::new(EllipticCylinder::with_radii(rx, ry)),
+        8 => Box::new(HyperbolicCylinder::with_radii(rx, ry)),
+        9 => Box::new(ParabolicCylinder::with_radius(rx)),
+        10 => Box::new(IntersectingPlanes::with_curvatures(
+            1.0 / (rx * rx),
+            1.0 / (ry * ry),
+        )),
+        11 => Box::new(ParallelPlanes::with_distance(rx)),
+        _ => Box::new(Ellipsoid::with_radii(rx, ry, rz)),
+    }
+}
+
+// Depth range of the bounding box in view coordinates, which bounds the ray march
+fn view_depth_range(view_transform: &AffineTransform, radii: [f64; 3]) -> (f64, f64) {
+    (0..8).fold((f64::INFINITY, f64::NEG_INFINITY), |(near, far), corner| {
+        let sign = |bit: usize| if corner & (1 << bit) == 0 { -1.0 } else { 1.0 };
+        let depth = (*view_transform
+            * Point::new(sign(0) * radii[0], sign(1) * radii[1], sign(2) * radii[2]))
+        .at(2);
+
+        (near.min(depth), far.max(depth))
+    })
+}
+
+unsafe fn set_superellipsoid_uniforms(
+    gl: &glow::Context,
+    program: glow::Program,
+    state: &State,
+    view_transform: &AffineTransform,
+    inverse_view_transform: &AffineTransform,
+) {
+    let superellipsoid = Superellipsoid::new(
+        state.rx,
+        state.ry,
+        state.rz,
+        state.horizontal_exponent,
+        state.vertical_exponent,
+    );
+    let radii = superellipsoid.radii();
+    let (horizontal_exponent, vertical_exponent) = superellipsoid.exponents();
+    let (near, far) = view_depth_range(view_transform, radii);
+
+    let view_to_form_location = gl.get_uniform_location(program, "view_to_form");
+    gl.uniform_matrix_4_f32_slice(
+        view_to_form_location.as_ref(),
+        true,
+        inverse_view_transform.with_type::<f32>().raw(),
+    );
+
+    let radii_location = gl.get_uniform_location(program, "radii");
+    gl.uniform_3_f32(
+        radii_location.as_ref(),
+        radii[0] as f32,
+        radii[1] as f32,
+        radii[2] as f32,
+    );
+
+    let exponents_location = gl.get_uniform_location(program, "exponents");
+    gl.uniform_2_f32(
+        exponents_location.as_ref(),
+        horizontal_exponent as f32,
+        vertical_exponent as f32,
+    );
+
+    let march_range_location = gl.get_uniform_location(program, "march_range");
+    gl.uniform_2_f32(march_range_location.as_ref(), near as f32, far as f32);
+}
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    }
+}
+
+fn create_program(gl: &glow::Context) -> Result<glow::Program> {
+    let mut shaders = [
+        (glow::VERTEX_SHADER, VERTEX_SHADER_SOURCE, None),
+        (glow::FRAGMENT_SHADER, FRAGMENT_SHADER_SOURCE, None),
+    ];
+
+    let program = unsafe { gl.create_program() }.map_err(Error::Shader)?;
 
     for (kind, source, handle) in &mut shaders {
         unsafe {
-            let shader = gl.create_shader(*kind).unwrap();
+            let shader = gl.create_shader(*kind).map_err(Error::Shader)?;
             gl.shader_source(shader, source);
             gl.compile_shader(shader);
 
             if !gl.get_shader_compile_status(shader) {
-                panic!("Error compiling shader: {}", gl.get_shader_info_log(shader));
+                return Err(Error::Shader(format!(
+                    "error compiling shader: {}",
+                    gl.get_shader_info_log(shader)
+                )));
             }
 
             gl.attach_shader(program, shader);
-            *handle = shader;
+            *handle = Some(shader);
         }
     }
 
     unsafe { gl.link_program(program) };
     if unsafe { !gl.get_program_link_status(program) } {
-        panic!("Error linking shader: {}", unsafe {
+        return Err(Error::Shader(format!("error linking shader: {}", unsafe {
             gl.get_program_info_log(program)
-        });
+        })));
     }
 
-    for &(_, _, shader) in &shaders {
+    for shader in shaders.iter().filter_map(|&(_, _, shader)| shader) {
         unsafe {
             gl.detach_shader(program, shader);
             gl.delete_shader(shader);
         }
     }
 
+    Ok(program)
+}
+
+fn run() -> Result<()> {
+    let (mut window, event_loop) = Window::try_new(WINDOW_TITLE, WINDOW_WIDTH, WINDOW_HEIGHT)?;
+    let mut last_frame = Instant::now();
+
+    let mut app_state = State {
+        form: 0,
+        rx: 0.5,
+        ry: 0.5,
+        rz: 0.5,
+        horizontal_exponent: 2.0,
+        vertical_exponent: 2.0,
+        divs: 1,
+        max_divs: 8,
+        light_intensity: 0.5,
+        shading: 0,
+        curvature_range: 1.0,
+        show_section: false,
+        section_height: 0.0,
+        section_tilt: 0.0,
+        left_mouse_button_down: false,
+        right_mouse_button_down: false,
+        current_mouse_position: None,
+        previous_mouse_position: None,
+        scroll_delta: 0.0,
+        resolution: glutin::dpi::PhysicalSize::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        camera_position: Point::new(0.0, 0.0, 1.0),
+        camera_basis: Matrix::from_data([
+            [-1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]),
+        scale: 1.0,
+    };
+
+    let gl = window.gl();
+
+    let vertex_array = unsafe { gl.create_vertex_array() }.map_err(Error::Renderer)?;
+    let program = create_program(gl)?;
+
     window.set_clear_color(CLEAR_COLOR);
 
     use glutin::event::{Event, WindowEvent};
@@ -239,10 +609,14 @@ fn main() {
             window.update_delta_time(duration);
             last_frame = now;
         }
-        Event::MainEventsCleared => window.request_redraw(),
+        Event::MainEventsCleared => {
+            if let Err(error) = window.request_redraw() {
+                eprintln!("{}", error);
+            }
+        }
         Event::RedrawRequested(_) => {
             let gl = window.gl();
-            let ellipsoid = Ellipsoid::with_radii(app_state.rx, app_state.ry, app_state.rz);
+            let form = selected_form(&app_state);
 
             unsafe {
                 gl.clear(glow::COLOR_BUFFER_BIT);
@@ -313,41 +687,92 @@ fn main() {
                     app_state.divs = app_state.max_divs;
                 }
 
-                let transform_matrix = app_state.camera_basis.inverse().unwrap()
-                    * affine::transforms::translate(-Vector::to_point(app_state.camera_position)) *
-                    affine::transforms::scale(app_state.scale as f64, app_state.scale as f64, app_state.scale as f64);
-
-                let inverse_transform = transform_matrix.inverse().unwrap();
-                let quadratic_form_matrix = inverse_transform.transpose()
-                    * ellipsoid.quadratic_form_matrix()
-                    * inverse_transform;
-
-                let quadratic_form_location = gl.get_uniform_location(program, "qf").unwrap();
-                gl.uniform_matrix_4_f32_slice(
-                    Some(&quadratic_form_location),
-                    true,
-                    quadratic_form_matrix.with_type::<f32>().raw(),
-                );
-
-                let light_intensity_location =
-                    gl.get_uniform_location(program, "light_intensity").unwrap();
-                gl.uniform_1_f32(Some(&light_intensity_location), app_state.light_intensity);
-
-                let divisions_location = gl.get_uniform_location(program, "divs").unwrap();
-                gl.uniform_1_i32(Some(&divisions_location), app_state.divs);
-
-                let resolution_location = gl.get_uniform_location(program, "resolution").unwrap();
-                gl.uniform_2_f32(
-                    Some(&resolution_location),
-                    app_state.resolution.width as f32,
-                    app_state.resolution.height as f32,
-                );
+                let view_form = app_state.camera_basis.try_inverse().and_then(|camera| {
+                    let transform_matrix = camera
+                        * affine::transforms::translate(-Vector::to_point(
+                            app_state.camera_position,
+                        ))
+                        * affine::transforms::scale(
+                            app_state.scale as f64,
+                            app_state.scale as f64,
+                            app_state.scale as f64,
+                        );
+
+                    Transformed::new(form, transform_matrix)
+                });
+
+                // A degenerate camera leaves the surface undrawn until it is moved again
+                if let Ok(view_form) = view_form {
+                    let quadratic_form_location = gl.get_uniform_location(program, "qf");
+                    gl.uniform_matrix_4_f32_slice(
+                        quadratic_form_location.as_ref(),
+                        true,
+                        view_form
+                            .quadric()
+                            .quadratic_form_matrix()
+                            .with_type::<f32>()
+                            .raw(),
+                    );
+
+                    let superellipsoid = app_state.form == SUPERELLIPSOID_FORM;
+                    let form_kind_location = gl.get_uniform_location(program, "form_kind");
+                    gl.uniform_1_i32(form_kind_location.as_ref(), superellipsoid as i32);
+
+                    if superellipsoid {
+                        set_superellipsoid_uniforms(
+                            gl,
+                            program,
+                            &app_state,
+                            &view_form.transform(),
+                            &view_form.inverse_transform(),
+                        );
+                    }
 
-                gl.bind_vertex_array(Some(vertex_array));
-                gl.draw_arrays(glow::TRIANGLES, 0, 6);
+                    let show_section_location = gl.get_uniform_location(program, "show_section");
+                    gl.uniform_1_i32(
+                        show_section_location.as_ref(),
+                        app_state.show_section as i32,
+                    );
+
+                    let [a, b, c, d] = view_section_plane(
+                        &section_plane(&app_state),
+                        &view_form.inverse_transform(),
+                    );
+                    let section_plane_location = gl.get_uniform_location(program, "section_plane");
+                    gl.uniform_4_f32(section_plane_location.as_ref(), a, b, c, d);
+
+                    let light_intensity_location =
+                        gl.get_uniform_location(program, "light_intensity");
+                    gl.uniform_1_f32(light_intensity_location.as_ref(), app_state.light_intensity);
+
+                    let divisions_location = gl.get_uniform_location(program, "divs");
+                    gl.uniform_1_i32(divisions_location.as_ref(), app_state.divs);
+
+                    let shading_location = gl.get_uniform_location(program, "shading");
+                    gl.uniform_1_i32(shading_location.as_ref(), app_state.shading as i32);
+
+                    let curvature_range_location =
+                        gl.get_uniform_location(program, "curvature_range");
+                    gl.uniform_1_f32(curvature_range_location.as_ref(), app_state.curvature_range);
+
+                    let world_scale_location = gl.get_uniform_location(program, "world_scale");
+                    gl.uniform_1_f32(world_scale_location.as_ref(), app_state.scale);
+
+                    let resolution_location = gl.get_uniform_location(program, "resolution");
+                    gl.uniform_2_f32(
+                        resolution_location.as_ref(),
+                        app_state.resolution.width as f32,
+                        app_state.resolution.height as f32,
+                    );
+
+                    gl.bind_vertex_array(Some(vertex_array));
+                    gl.draw_arrays(glow::TRIANGLES, 0, 6);
+                }
             }
 
-            window.render(|ui| build_ui(ui, &mut app_state));
+            if let Err(error) = window.render(|ui| build_ui(ui, &mut app_state)) {
+                eprintln!("{}", error);
+            }
         }
         Event::WindowEvent {
             event:
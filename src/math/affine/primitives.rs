@@ -1,4 +1,9 @@
-use crate::math::matrix::Matrix;
+use crate::{
+    error::{Error, Result},
+    math::matrix::Matrix,
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 type AffineElement = Matrix<f64, 4, 1>;
 type TransposedAffineElement = Matrix<f64, 1, 4>;
@@ -44,6 +49,14 @@ impl Point {
         Point { affine }
     }
 
+    pub fn try_from_affine(affine: AffineElement) -> Result<Point> {
+        if affine.at(3, 0) != 1.0 {
+            return Err(Error::NotAPoint { w: affine.at(3, 0) });
+        }
+
+        Ok(Point { affine })
+    }
+
     impl_affine_basics!();
 }
 
@@ -73,10 +86,34 @@ impl Vector {
         Vector { affine }
     }
 
+    pub fn try_from_affine(affine: AffineElement) -> Result<Vector> {
+        if affine.at(3, 0) != 0.0 {
+            return Err(Error::NotAVector { w: affine.at(3, 0) });
+        }
+
+        Ok(Vector { affine })
+    }
+
+    pub fn cross(&self, rhs: Vector) -> Vector {
+        Vector::new(
+            self.at(1) * rhs.at(2) - self.at(2) * rhs.at(1),
+            self.at(2) * rhs.at(0) - self.at(0) * rhs.at(2),
+            self.at(0) * rhs.at(1) - self.at(1) * rhs.at(0),
+        )
+    }
+
+    pub fn length(&self) -> f64 {
+        (*self * *self).sqrt()
+    }
+
+    pub fn normalized(&self) -> Vector {
+        *self * (1.0 / self.length())
+    }
+
     impl_affine_basics!();
 }
 
-impl std::ops::Mul<Vector> for Vector {
+impl core::ops::Mul<Vector> for Vector {
     type Output = f64;
 
     fn mul(self, rhs: Vector) -> Self::Output {
@@ -86,7 +123,7 @@ impl std::ops::Mul<Vector> for Vector {
 
 macro_rules! impl_affine_add {
     ($type1:ident + $type2:ident -> $type_out:ident) => {
-        impl std::ops::Add<$type2> for $type1 {
+        impl core::ops::Add<$type2> for $type1 {
             type Output = $type_out;
 
             fn add(self, rhs: $type2) -> Self::Output {
@@ -102,7 +139,35 @@ impl_affine_add!(Vector + Vector -> Vector);
 impl_affine_add!(Vector + Point -> Point);
 impl_affine_add!(Point + Vector -> Point);
 
-impl std::ops::Neg for Vector {
+macro_rules! impl_affine_sub {
+    ($type1:ident - $type2:ident -> $type_out:ident) => {
+        impl core::ops::Sub<$type2> for $type1 {
+            type Output = $type_out;
+
+            fn sub(self, rhs: $type2) -> Self::Output {
+                $type_out::new(
+                    self.at(0) - rhs.at(0),
+                    self.at(1) - rhs.at(1),
+                    self.at(2) - rhs.at(2),
+                )
+            }
+        }
+    };
+}
+
+impl_affine_sub!(Vector - Vector -> Vector);
+impl_affine_sub!(Point - Vector -> Point);
+impl_affine_sub!(Point - Point -> Vector);
+
+impl core::ops::Mul<f64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Vector::new(self.at(0) * rhs, self.at(1) * rhs, self.at(2) * rhs)
+    }
+}
+
+impl core::ops::Neg for Vector {
     type Output = Vector;
 
     fn neg(self) -> Self::Output {
@@ -110,10 +175,38 @@ impl std::ops::Neg for Vector {
     }
 }
 
-impl std::ops::Neg for Point {
+impl core::ops::Neg for Point {
     type Output = Point;
 
     fn neg(self) -> Self::Output {
         Point::new(-self.at(0), -self.at(1), -self.at(2))
     }
 }
+
+#[cfg(feature = "serde")]
+macro_rules! impl_affine_serde {
+    ($type:ident) => {
+        impl serde::Serialize for $type {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> core::result::Result<S::Ok, S::Error> {
+                [self.at(0), self.at(1), self.at(2)].serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> core::result::Result<Self, D::Error> {
+                let [x, y, z] = <[f64; 3]>::deserialize(deserializer)?;
+                Ok($type::new(x, y, z))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_affine_serde!(Point);
+#[cfg(feature = "serde")]
+impl_affine_serde!(Vector);
@@ -2,10 +2,12 @@ use crate::math::{
     affine::primitives::{Point, Vector},
     matrix::Matrix,
 };
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 pub type AffineTransform = Matrix<f64, 4, 4>;
 
-impl std::ops::Mul<Point> for AffineTransform {
+impl core::ops::Mul<Point> for AffineTransform {
     type Output = Point;
 
     fn mul(self, rhs: Point) -> Self::Output {
@@ -13,7 +15,7 @@ impl std::ops::Mul<Point> for AffineTransform {
     }
 }
 
-impl std::ops::Mul<Vector> for AffineTransform {
+impl core::ops::Mul<Vector> for AffineTransform {
     type Output = Vector;
 
     fn mul(self, rhs: Vector) -> Self::Output {
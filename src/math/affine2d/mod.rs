@@ -0,0 +1,2 @@
+pub mod primitives;
+pub mod transforms;
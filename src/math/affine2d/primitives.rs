@@ -0,0 +1,213 @@
+use crate::{
+    error::{Error, Result},
+    math::matrix::Matrix,
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+type AffineElement = Matrix<f64, 3, 1>;
+type TransposedAffineElement = Matrix<f64, 1, 3>;
+
+// Points and vectors of the plane in homogeneous coordinates, the 2D counterparts of
+// the affine primitives
+#[derive(Clone, Copy, Debug)]
+pub struct Point2 {
+    affine: AffineElement,
+}
+
+macro_rules! impl_affine_basics {
+    () => {
+        pub fn at(&self, i: usize) -> f64 {
+            self.affine.at(i, 0)
+        }
+
+        pub fn at_mut(&mut self, i: usize) -> &mut f64 {
+            self.affine.at_mut(i, 0)
+        }
+
+        pub fn as_matrix(&self) -> AffineElement {
+            self.affine
+        }
+
+        pub fn as_transpose(&self) -> TransposedAffineElement {
+            self.affine.transpose()
+        }
+    };
+}
+
+impl Point2 {
+    pub fn new(x: f64, y: f64) -> Point2 {
+        Point2 {
+            affine: AffineElement::from_data([[x], [y], [1.0]]),
+        }
+    }
+
+    pub fn from_affine(affine: AffineElement) -> Point2 {
+        assert!(
+            affine.at(2, 0) == 1.0,
+            "creating point from an affine element {:?}",
+            affine
+        );
+        Point2 { affine }
+    }
+
+    pub fn try_from_affine(affine: AffineElement) -> Result<Point2> {
+        if affine.at(2, 0) != 1.0 {
+            return Err(Error::NotAPoint { w: affine.at(2, 0) });
+        }
+
+        Ok(Point2 { affine })
+    }
+
+    impl_affine_basics!();
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Vector2 {
+    affine: AffineElement,
+}
+
+impl Vector2 {
+    pub fn new(x: f64, y: f64) -> Vector2 {
+        Vector2 {
+            affine: AffineElement::from_data([[x], [y], [0.0]]),
+        }
+    }
+
+    pub fn to_point(point: Point2) -> Vector2 {
+        Self::new(point.at(0), point.at(1))
+    }
+
+    pub fn from_affine(affine: AffineElement) -> Vector2 {
+        assert!(
+            affine.at(2, 0) == 0.0,
+            "creating vector from an affine element {:?}",
+            affine
+        );
+
+        Vector2 { affine }
+    }
+
+    pub fn try_from_affine(affine: AffineElement) -> Result<Vector2> {
+        if affine.at(2, 0) != 0.0 {
+            return Err(Error::NotAVector { w: affine.at(2, 0) });
+        }
+
+        Ok(Vector2 { affine })
+    }
+
+    // The z component of the cross product in space, positive when rhs is
+    // counterclockwise from self
+    pub fn cross(&self, rhs: Vector2) -> f64 {
+        self.at(0) * rhs.at(1) - self.at(1) * rhs.at(0)
+    }
+
+    // Rotated a quarter turn counterclockwise
+    pub fn perpendicular(&self) -> Vector2 {
+        Vector2::new(-self.at(1), self.at(0))
+    }
+
+    pub fn length(&self) -> f64 {
+        (*self * *self).sqrt()
+    }
+
+    pub fn normalized(&self) -> Vector2 {
+        *self * (1.0 / self.length())
+    }
+
+    impl_affine_basics!();
+}
+
+impl core::ops::Mul<Vector2> for Vector2 {
+    type Output = f64;
+
+    fn mul(self, rhs: Vector2) -> Self::Output {
+        (self.affine.transpose() * rhs.affine).num()
+    }
+}
+
+macro_rules! impl_affine_add {
+    ($type1:ident + $type2:ident -> $type_out:ident) => {
+        impl core::ops::Add<$type2> for $type1 {
+            type Output = $type_out;
+
+            fn add(self, rhs: $type2) -> Self::Output {
+                Self::Output {
+                    affine: self.affine + rhs.affine,
+                }
+            }
+        }
+    };
+}
+
+impl_affine_add!(Vector2 + Vector2 -> Vector2);
+impl_affine_add!(Vector2 + Point2 -> Point2);
+impl_affine_add!(Point2 + Vector2 -> Point2);
+
+macro_rules! impl_affine_sub {
+    ($type1:ident - $type2:ident -> $type_out:ident) => {
+        impl core::ops::Sub<$type2> for $type1 {
+            type Output = $type_out;
+
+            fn sub(self, rhs: $type2) -> Self::Output {
+                $type_out::new(self.at(0) - rhs.at(0), self.at(1) - rhs.at(1))
+            }
+        }
+    };
+}
+
+impl_affine_sub!(Vector2 - Vector2 -> Vector2);
+impl_affine_sub!(Point2 - Vector2 -> Point2);
+impl_affine_sub!(Point2 - Point2 -> Vector2);
+
+impl core::ops::Mul<f64> for Vector2 {
+    type Output = Vector2;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Vector2::new(self.at(0) * rhs, self.at(1) * rhs)
+    }
+}
+
+impl core::ops::Neg for Vector2 {
+    type Output = Vector2;
+
+    fn neg(self) -> Self::Output {
+        Vector2::new(-self.at(0), -self.at(1))
+    }
+}
+
+impl core::ops::Neg for Point2 {
+    type Output = Point2;
+
+    fn neg(self) -> Self::Output {
+        Point2::new(-self.at(0), -self.at(1))
+    }
+}
+
+#[cfg(feature = "serde")]
+macro_rules! impl_affine_serde {
+    ($type:ident) => {
+        impl serde::Serialize for $type {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> core::result::Result<S::Ok, S::Error> {
+                [self.at(0), self.at(1)].serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> core::result::Result<Self, D::Error> {
+                let [x, y] = <[f64; 2]>::deserialize(deserializer)?;
+                Ok($type::new(x, y))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_affine_serde!(Point2);
+#[cfg(feature = "serde")]
+impl_affine_serde!(Vector2);
@@ -0,0 +1,56 @@
+use crate::math::{
+    affine2d::primitives::{Point2, Vector2},
+    matrix::Matrix,
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+pub type AffineTransform2 = Matrix<f64, 3, 3>;
+
+impl core::ops::Mul<Point2> for AffineTransform2 {
+    type Output = Point2;
+
+    fn mul(self, rhs: Point2) -> Self::Output {
+        Point2::from_affine(self * rhs.as_matrix())
+    }
+}
+
+impl core::ops::Mul<Vector2> for AffineTransform2 {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Self::Output {
+        Vector2::from_affine(self * rhs.as_matrix())
+    }
+}
+
+pub fn rotate(angle: f64) -> AffineTransform2 {
+    let mut rotation = Matrix::zero();
+
+    *rotation.at_mut(2, 2) = 1.0;
+
+    *rotation.at_mut(0, 0) = angle.cos();
+    *rotation.at_mut(0, 1) = -angle.sin();
+    *rotation.at_mut(1, 0) = angle.sin();
+    *rotation.at_mut(1, 1) = angle.cos();
+
+    rotation
+}
+
+pub fn translate(vector: Vector2) -> AffineTransform2 {
+    let mut translation = Matrix::identity();
+
+    *translation.at_mut(0, 2) = vector.at(0);
+    *translation.at_mut(1, 2) = vector.at(1);
+
+    translation
+}
+
+pub fn scale(sx: f64, sy: f64) -> AffineTransform2 {
+    let mut scaling = Matrix::zero();
+
+    *scaling.at_mut(0, 0) = sx;
+    *scaling.at_mut(1, 1) = sy;
+    *scaling.at_mut(2, 2) = 1.0;
+
+    scaling
+}
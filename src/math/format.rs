@@ -0,0 +1,184 @@
+use crate::math::matrix::Matrix;
+use core::fmt::{self, Display, Formatter, Write};
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MatrixStyle {
+    #[default]
+    Plain,
+    Latex,
+    Markdown,
+    NumPy,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MatrixFormat {
+    pub precision: Option<usize>,
+    pub align_columns: bool,
+    pub style: MatrixStyle,
+}
+
+impl MatrixFormat {
+    pub fn with_style(style: MatrixStyle) -> MatrixFormat {
+        MatrixFormat {
+            style,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for MatrixFormat {
+    fn default() -> MatrixFormat {
+        MatrixFormat {
+            precision: None,
+            align_columns: false,
+            style: MatrixStyle::Plain,
+        }
+    }
+}
+
+pub struct FormattedMatrix<'a, T: Float, const M: usize, const N: usize> {
+    matrix: &'a Matrix<T, M, N>,
+    format: MatrixFormat,
+}
+
+impl<T: Float, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn format(&self, format: MatrixFormat) -> FormattedMatrix<'_, T, M, N> {
+        FormattedMatrix {
+            matrix: self,
+            format,
+        }
+    }
+}
+
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> FormattedMatrix<'a, T, M, N>
+where
+    T: Float + Display,
+{
+    fn write_value<W: Write>(&self, writer: &mut W, value: T, width: usize) -> fmt::Result {
+        match self.format.precision {
+            Some(precision) => write!(writer, "{:>width$.precision$}", value),
+            None => write!(writer, "{:>width$}", value),
+        }
+    }
+
+    fn column_widths(&self) -> [usize; N] {
+        let mut widths = [0; N];
+
+        if self.format.align_columns {
+            for row in 0..M {
+                for (col, width) in widths.iter_mut().enumerate() {
+                    let mut counter = CharCounter(0);
+                    // Writing into a counter cannot fail
+                    let _ = self.write_value(&mut counter, self.matrix.at(row, col), 0);
+                    *width = core::cmp::max(*width, counter.0);
+                }
+            }
+        }
+
+        widths
+    }
+
+    fn write_rows(
+        &self,
+        formatter: &mut Formatter,
+        row_start: &str,
+        separator: &str,
+        row_end: &str,
+        row_separator: &str,
+    ) -> fmt::Result {
+        let widths = self.column_widths();
+
+        for row in 0..M {
+            formatter.write_str(row_start)?;
+
+            for (col, &width) in widths.iter().enumerate() {
+                if col != 0 {
+                    formatter.write_str(separator)?;
+                }
+
+                self.write_value(formatter, self.matrix.at(row, col), width)?;
+            }
+
+            formatter.write_str(row_end)?;
+
+            if row != M - 1 {
+                formatter.write_str(row_separator)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> Display for FormattedMatrix<'a, T, M, N>
+where
+    T: Float + Display,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self.format.style {
+            MatrixStyle::Plain => {
+                formatter.write_str("[")?;
+                self.write_rows(formatter, "[", ", ", "]", "\n ")?;
+                formatter.write_str("]\n")
+            }
+            MatrixStyle::Latex => {
+                formatter.write_str("\\begin{bmatrix}\n")?;
+                self.write_rows(formatter, "", " & ", "", " \\\\\n")?;
+                formatter.write_str("\n\\end{bmatrix}")
+            }
+            MatrixStyle::Markdown => {
+                // Markdown tables need a header, which numbers the columns
+                for col in 0..N {
+                    write!(formatter, "| {} ", col)?;
+                }
+                formatter.write_str("|\n")?;
+
+                for _ in 0..N {
+                    formatter.write_str("|---")?;
+                }
+                formatter.write_str("|\n")?;
+
+                self.write_rows(formatter, "| ", " | ", " |", "\n")
+            }
+            MatrixStyle::NumPy => {
+                formatter.write_str("np.array([")?;
+                self.write_rows(formatter, "[", ", ", "]", ",\n          ")?;
+                formatter.write_str("])")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn display_is_unaligned_by_default() {
+        let matrix = Matrix::from_data([[1.0, -2.5], [10.0, 4.0]]);
+
+        assert_eq!(format!("{}", matrix), "[[1, -2.5]\n [10, 4]]\n");
+    }
+
+    #[test]
+    fn markdown_header_numbers_the_columns() {
+        let matrix = Matrix::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let table = format!(
+            "{}",
+            matrix.format(MatrixFormat::with_style(MatrixStyle::Markdown))
+        );
+
+        assert_eq!(table, "| 0 | 1 |\n|---|---|\n| 1 | 2 |\n| 3 | 4 |");
+    }
+}
@@ -1,5 +1,9 @@
+use crate::{
+    error::{Error, Result},
+    math::{format::MatrixFormat, simd},
+};
+use alloc::vec::Vec;
 use num_traits::Float;
-use std;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Matrix<T: Float, const M: usize, const N: usize> {
@@ -18,7 +22,7 @@ impl<T: Float + Copy, const M: usize, const N: usize> Matrix<T, M, N> {
 
     pub fn raw(&self) -> &[T] {
         // TODO: use `slice::flatten` if it ever gets into stable
-        unsafe { std::slice::from_raw_parts(self.data.as_ptr().cast(), N * M) }
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr().cast(), N * M) }
     }
 
     pub fn at(&self, row: usize, col: usize) -> T {
@@ -37,12 +41,28 @@ impl<T: Float + Copy, const M: usize, const N: usize> Matrix<T, M, N> {
         let mut col = Matrix::zero();
 
         for j in 0..M {
-            col.data[0][j] = self.data[i][j];
+            col.data[j][0] = self.data[j][i];
         }
 
         col
     }
 
+    pub fn submatrix<const P: usize, const Q: usize>(
+        &self,
+        first_row: usize,
+        first_col: usize,
+    ) -> Matrix<T, P, Q> {
+        let mut result = Matrix::zero();
+
+        for row in 0..P {
+            for col in 0..Q {
+                result.data[row][col] = self.data[first_row + row][first_col + col];
+            }
+        }
+
+        result
+    }
+
     pub fn transpose(&self) -> Matrix<T, N, M> {
         let mut result = Matrix::zero();
 
@@ -99,18 +119,16 @@ impl<T: Float + Copy, const M: usize, const N: usize> Matrix<T, M, N> {
         let mut gepp_matrix = *self;
         let mut operations = Vec::with_capacity(M * N);
 
-        for i in 0..std::cmp::min(M, N) {
+        for i in 0..core::cmp::min(M, N) {
             let mut pivot = i;
 
-            while gepp_matrix.data[i][pivot] == T::from(0.0).unwrap() {
-                pivot += 1;
-
-                if pivot >= M {
-                    return None;
+            for row in (i + 1)..M {
+                if gepp_matrix.data[row][i].abs() > gepp_matrix.data[pivot][i].abs() {
+                    pivot = row;
                 }
             }
 
-            if pivot == N {
+            if gepp_matrix.data[pivot][i] == T::from(0.0).unwrap() {
                 return None;
             }
 
@@ -149,6 +167,100 @@ impl<T: Float, const M: usize> Matrix<T, M, M> {
         result
     }
 
+    pub fn trace(&self) -> T {
+        (0..M).fold(T::from(0.0).unwrap(), |sum, i| sum + self.data[i][i])
+    }
+
+    // Cyclic Jacobi rotations; eigenvalues are sorted in descending order and the
+    // corresponding unit eigenvectors are the columns of the returned matrix
+    pub fn symmetric_eigen(&self) -> ([T; M], Matrix<T, M, M>) {
+        const MAX_SWEEPS: usize = 64;
+
+        let zero = T::from(0.0).unwrap();
+        let one = T::from(1.0).unwrap();
+        let two = T::from(2.0).unwrap();
+
+        let mut diagonalized = *self;
+        let mut vectors = Matrix::<T, M, M>::identity();
+
+        let norm = self
+            .data
+            .iter()
+            .flatten()
+            .fold(zero, |sum, &value| sum + value * value);
+
+        for _ in 0..MAX_SWEEPS {
+            let mut off_diagonal = zero;
+            for p in 0..M {
+                for q in (p + 1)..M {
+                    off_diagonal = off_diagonal + diagonalized.data[p][q] * diagonalized.data[p][q];
+                }
+            }
+
+            if off_diagonal <= T::epsilon() * T::epsilon() * norm {
+                break;
+            }
+
+            for p in 0..M {
+                for q in (p + 1)..M {
+                    let apq = diagonalized.data[p][q];
+                    if apq == zero {
+                        continue;
+                    }
+
+                    let theta = (diagonalized.data[q][q] - diagonalized.data[p][p]) / (two * apq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + one).sqrt());
+                    let c = one / (t * t + one).sqrt();
+                    let s = t * c;
+
+                    for k in 0..M {
+                        let akp = diagonalized.data[k][p];
+                        let akq = diagonalized.data[k][q];
+                        diagonalized.data[k][p] = c * akp - s * akq;
+                        diagonalized.data[k][q] = s * akp + c * akq;
+                    }
+
+                    for k in 0..M {
+                        let apk = diagonalized.data[p][k];
+                        let aqk = diagonalized.data[q][k];
+                        diagonalized.data[p][k] = c * apk - s * aqk;
+                        diagonalized.data[q][k] = s * apk + c * aqk;
+                    }
+
+                    for k in 0..M {
+                        let vkp = vectors.data[k][p];
+                        let vkq = vectors.data[k][q];
+                        vectors.data[k][p] = c * vkp - s * vkq;
+                        vectors.data[k][q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        let mut order = [0; M];
+        for (i, index) in order.iter_mut().enumerate() {
+            *index = i;
+        }
+        order.sort_by(|&i, &j| {
+            diagonalized.data[j][j]
+                .partial_cmp(&diagonalized.data[i][i])
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        let mut values = [zero; M];
+        let mut sorted_vectors = Matrix::zero();
+
+        for (target, &source) in order.iter().enumerate() {
+            values[target] = diagonalized.data[source][source];
+
+            for k in 0..M {
+                sorted_vectors.data[k][target] = vectors.data[k][source];
+            }
+        }
+
+        (values, sorted_vectors)
+    }
+
     pub fn diagonalization_of_gaussed(&self) -> Vec<RowOp<T>> {
         let mut diagonal = *self;
         let mut row_ops = Vec::new();
@@ -176,9 +288,27 @@ impl<T: Float, const M: usize> Matrix<T, M, M> {
         Some(constant_terms)
     }
 
-    pub fn inverse(&self) -> Option<Matrix<T, M, M>> {
+    pub fn inverse(&self) -> Option<Matrix<T, M, M>>
+    where
+        T: 'static,
+    {
+        // Outer `None` means there is no specialised kernel for this size and type
+        match simd::specialized_inverse(self) {
+            Some(inverse) => inverse,
+            None => self.generic_inverse(),
+        }
+    }
+
+    pub fn generic_inverse(&self) -> Option<Matrix<T, M, M>> {
         self.solve_linear_system(Matrix::<T, M, M>::identity())
     }
+
+    pub fn try_inverse(&self) -> Result<Matrix<T, M, M>>
+    where
+        T: 'static,
+    {
+        self.inverse().ok_or(Error::SingularMatrix)
+    }
 }
 
 impl<T: Float> Matrix<T, 1, 1> {
@@ -187,7 +317,7 @@ impl<T: Float> Matrix<T, 1, 1> {
     }
 }
 
-impl<T: Float, const M: usize, const N: usize> std::ops::Add<Matrix<T, M, N>> for Matrix<T, M, N> {
+impl<T: Float, const M: usize, const N: usize> core::ops::Add<Matrix<T, M, N>> for Matrix<T, M, N> {
     type Output = Matrix<T, M, N>;
 
     fn add(self, rhs: Matrix<T, M, N>) -> Self::Output {
@@ -203,13 +333,9 @@ impl<T: Float, const M: usize, const N: usize> std::ops::Add<Matrix<T, M, N>> fo
     }
 }
 
-impl<T: Float + std::ops::AddAssign<T>, const M: usize, const N: usize, const L: usize>
-    std::ops::Mul<Matrix<T, N, L>> for Matrix<T, M, N>
-{
-    type Output = Matrix<T, M, L>;
-
-    fn mul(self, rhs: Matrix<T, N, L>) -> Self::Output {
-        let mut result = Self::Output::zero();
+impl<T: Float + core::ops::AddAssign<T>, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn generic_mul<const L: usize>(&self, rhs: &Matrix<T, N, L>) -> Matrix<T, M, L> {
+        let mut result = Matrix::zero();
 
         for i in 0..M {
             for j in 0..L {
@@ -223,32 +349,29 @@ impl<T: Float + std::ops::AddAssign<T>, const M: usize, const N: usize, const L:
     }
 }
 
-impl<T, const M: usize, const N: usize> std::fmt::Display for Matrix<T, M, N>
+impl<T, const M: usize, const N: usize, const L: usize> core::ops::Mul<Matrix<T, N, L>>
+    for Matrix<T, M, N>
 where
-    T: Float + std::fmt::Display,
+    T: Float + core::ops::AddAssign<T> + 'static,
 {
-    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let write_row = |row: usize, formatter: &mut std::fmt::Formatter| -> std::fmt::Result {
-            write!(formatter, "[")?;
-            for col in 0..(N - 1) {
-                write!(formatter, "{}, ", self.data[row][col])?;
-            }
-
-            write!(formatter, "{}]", self.data[row][N - 1])?;
-            Ok(())
-        };
-
-        write!(formatter, "[")?;
+    type Output = Matrix<T, M, L>;
 
-        for row in 0..(M - 1) {
-            write_row(row, formatter)?;
-            write!(formatter, "\n ")?;
-        }
+    fn mul(self, rhs: Matrix<T, N, L>) -> Self::Output {
+        simd::specialized_mul(&self, &rhs).unwrap_or_else(|| self.generic_mul(&rhs))
+    }
+}
 
-        write_row(M - 1, formatter)?;
-        writeln!(formatter, "]")?;
+impl<T, const M: usize, const N: usize> core::fmt::Display for Matrix<T, M, N>
+where
+    T: Float + core::fmt::Display,
+{
+    fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let format = MatrixFormat {
+            precision: formatter.precision(),
+            ..Default::default()
+        };
 
-        Ok(())
+        write!(formatter, "{}", self.format(format))
     }
 }
 
@@ -258,3 +381,141 @@ pub enum RowOp<T: Float> {
     Add(usize, usize, T),
     Mul(usize, T),
 }
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::Matrix;
+    use num_traits::Float;
+    use serde::{
+        de::{DeserializeSeed, Error, IgnoredAny, SeqAccess, Visitor},
+        ser::SerializeSeq,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    struct Row<'a, T, const N: usize>(&'a [T; N]);
+
+    impl<'a, T: Serialize, const N: usize> Serialize for Row<'a, T, N> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(N))?;
+            for value in self.0 {
+                seq.serialize_element(value)?;
+            }
+
+            seq.end()
+        }
+    }
+
+    impl<T, const M: usize, const N: usize> Serialize for Matrix<T, M, N>
+    where
+        T: Float + Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(M))?;
+            for row in &self.data {
+                seq.serialize_element(&Row(row))?;
+            }
+
+            seq.end()
+        }
+    }
+
+    struct RowVisitor<'a, T, const N: usize>(&'a mut [T; N]);
+
+    impl<'de, 'a, T, const N: usize> Visitor<'de> for RowVisitor<'a, T, N>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = ();
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(formatter, "a matrix row of {} elements", N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+            for (col, value) in self.0.iter_mut().enumerate() {
+                *value = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(col, &"a full matrix row"))?;
+            }
+
+            if seq.next_element::<IgnoredAny>()?.is_some() {
+                return Err(Error::invalid_length(N + 1, &"a full matrix row"));
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<'de, 'a, T, const N: usize> DeserializeSeed<'de> for RowVisitor<'a, T, N>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = ();
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_seq(self)
+        }
+    }
+
+    struct MatrixVisitor<T, const M: usize, const N: usize>(core::marker::PhantomData<T>);
+
+    impl<'de, T, const M: usize, const N: usize> Visitor<'de> for MatrixVisitor<T, M, N>
+    where
+        T: Float + Deserialize<'de>,
+    {
+        type Value = Matrix<T, M, N>;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(formatter, "a {}x{} matrix as a sequence of rows", M, N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut matrix = Matrix::zero();
+
+            for row in 0..M {
+                seq.next_element_seed(RowVisitor(&mut matrix.data[row]))?
+                    .ok_or_else(|| Error::invalid_length(row, &self))?;
+            }
+
+            if seq.next_element::<IgnoredAny>()?.is_some() {
+                return Err(Error::invalid_length(M + 1, &self));
+            }
+
+            Ok(matrix)
+        }
+    }
+
+    impl<'de, T, const M: usize, const N: usize> Deserialize<'de> for Matrix<T, M, N>
+    where
+        T: Float + Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(MatrixVisitor(core::marker::PhantomData))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trips_rows() {
+        let matrix = Matrix::from_data([[1.0, -2.5, 0.125], [3.0, 1e-17, -7.0]]);
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(json, "[[1.0,-2.5,0.125],[3.0,1e-17,-7.0]]");
+
+        let read: Matrix<f64, 2, 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(read.raw(), matrix.raw());
+    }
+
+    #[test]
+    fn serde_rejects_wrong_dimensions() {
+        assert!(serde_json::from_str::<Matrix<f64, 2, 2>>("[[1.0, 2.0], [3.0]]").is_err());
+        assert!(
+            serde_json::from_str::<Matrix<f64, 2, 2>>("[[1.0, 2.0, 3.0], [3.0, 4.0]]").is_err()
+        );
+        assert!(serde_json::from_str::<Matrix<f64, 2, 2>>("[[1.0, 2.0]]").is_err());
+        assert!(serde_json::from_str::<Matrix<f64, 1, 2>>("[[1.0, 2.0], [3.0, 4.0]]").is_err());
+    }
+}
@@ -1,2 +1,7 @@
-pub mod matrix;
 pub mod affine;
+pub mod affine2d;
+pub mod format;
+pub mod matrix;
+pub mod parse;
+pub mod roots;
+pub mod simd;
@@ -0,0 +1,292 @@
+use crate::math::{
+    affine::primitives::{Point, Vector},
+    matrix::Matrix,
+};
+use core::{fmt, str::FromStr};
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseMatrixError {
+    UnbalancedBrackets,
+    UnexpectedCharacter(char),
+    InvalidNumber {
+        row: usize,
+        col: usize,
+    },
+    WrongRowCount {
+        expected: usize,
+        found: usize,
+    },
+    WrongColumnCount {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseMatrixError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseMatrixError::UnbalancedBrackets => write!(formatter, "unbalanced brackets"),
+            ParseMatrixError::UnexpectedCharacter(c) => {
+                write!(formatter, "unexpected character '{}'", c)
+            }
+            ParseMatrixError::InvalidNumber { row, col } => {
+                write!(formatter, "invalid number at row {}, column {}", row, col)
+            }
+            ParseMatrixError::WrongRowCount { expected, found } => {
+                write!(formatter, "expected {} rows, found {}", expected, found)
+            }
+            ParseMatrixError::WrongColumnCount {
+                row,
+                expected,
+                found,
+            } => write!(
+                formatter,
+                "expected {} columns in row {}, found {}",
+                expected, row, found
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseMatrixError {}
+
+struct RowCollector<T: Float, const M: usize, const N: usize> {
+    matrix: Matrix<T, M, N>,
+    rows: usize,
+}
+
+impl<T: Float + FromStr, const M: usize, const N: usize> RowCollector<T, M, N> {
+    fn push_row(&mut self, text: &str) -> Result<(), ParseMatrixError> {
+        let row = self.rows;
+        self.rows += 1;
+
+        if row >= M {
+            return Ok(());
+        }
+
+        let mut found = 0;
+        for token in text
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|token| !token.is_empty())
+        {
+            if found < N {
+                *self.matrix.at_mut(row, found) = token
+                    .parse()
+                    .map_err(|_| ParseMatrixError::InvalidNumber { row, col: found })?;
+            }
+
+            found += 1;
+        }
+
+        if found != N {
+            return Err(ParseMatrixError::WrongColumnCount {
+                row,
+                expected: N,
+                found,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn push_bracketed_rows(&mut self, mut text: &str) -> Result<(), ParseMatrixError> {
+        loop {
+            text = text.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+
+            let Some(c) = text.chars().next() else {
+                return Ok(());
+            };
+
+            if c != '[' {
+                return Err(ParseMatrixError::UnexpectedCharacter(c));
+            }
+
+            let end = text.find(']').ok_or(ParseMatrixError::UnbalancedBrackets)?;
+            let row = &text[1..end];
+
+            if row.contains('[') {
+                return Err(ParseMatrixError::UnbalancedBrackets);
+            }
+
+            self.push_row(row)?;
+            text = &text[end + 1..];
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> FromStr for Matrix<T, M, N>
+where
+    T: Float + FromStr,
+{
+    type Err = ParseMatrixError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let mut collector = RowCollector {
+            matrix: Matrix::zero(),
+            rows: 0,
+        };
+
+        if let Some(inner) = text.strip_prefix('[') {
+            let inner = inner
+                .strip_suffix(']')
+                .ok_or(ParseMatrixError::UnbalancedBrackets)?;
+
+            if inner.contains('[') {
+                collector.push_bracketed_rows(inner)?;
+            } else {
+                collector.push_row(inner)?;
+            }
+        } else {
+            for line in text.split(['\n', ';']) {
+                if !line.trim().is_empty() {
+                    collector.push_row(line)?;
+                }
+            }
+        }
+
+        if collector.rows != M {
+            return Err(ParseMatrixError::WrongRowCount {
+                expected: M,
+                found: collector.rows,
+            });
+        }
+
+        Ok(collector.matrix)
+    }
+}
+
+macro_rules! impl_affine_from_str {
+    ($type:ident) => {
+        impl FromStr for $type {
+            type Err = ParseMatrixError;
+
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                let coords = Matrix::<f64, 1, 3>::from_str(text)?;
+                Ok($type::new(
+                    coords.at(0, 0),
+                    coords.at(0, 1),
+                    coords.at(0, 2),
+                ))
+            }
+        }
+    };
+}
+
+impl_affine_from_str!(Point);
+impl_affine_from_str!(Vector);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::format::MatrixFormat;
+    use alloc::{format, string::ToString};
+
+    fn parsed<const M: usize, const N: usize>(text: &str) -> Matrix<f64, M, N> {
+        text.parse()
+            .unwrap_or_else(|error| panic!("{:?} for {:?}", error, text))
+    }
+
+    #[test]
+    fn formatted_matrices_parse_back() {
+        let matrix = Matrix::from_data([[1.0, -2.5, 1e-3], [10.0, 0.0, -7.25]]);
+        let formats = [
+            MatrixFormat::default(),
+            MatrixFormat {
+                align_columns: true,
+                ..Default::default()
+            },
+            MatrixFormat {
+                precision: Some(4),
+                align_columns: true,
+                ..Default::default()
+            },
+        ];
+
+        for format in formats {
+            let text = format!("{}", matrix.format(format));
+            assert_eq!(parsed::<2, 3>(&text).raw(), matrix.raw());
+        }
+        assert_eq!(parsed::<2, 3>(&matrix.to_string()).raw(), matrix.raw());
+    }
+
+    #[test]
+    fn whitespace_and_flat_formats_parse() {
+        let expected = [1.0, 2.0, 3.0, 4.0];
+
+        assert_eq!(parsed::<2, 2>("1 2\n3 4").raw(), expected);
+        assert_eq!(parsed::<2, 2>("1, 2; 3, 4").raw(), expected);
+        assert_eq!(parsed::<2, 2>("  [[1,2],\n   [3,4]]  ").raw(), expected);
+        assert_eq!(parsed::<1, 4>("[1 2 3 4]").raw(), expected);
+
+        let point: Point = "[1.5, -2, 3e2]".parse().unwrap();
+        let vector: Vector = "0 1 0".parse().unwrap();
+        assert_eq!([point.at(0), point.at(1), point.at(2)], [1.5, -2.0, 300.0]);
+        assert_eq!([vector.at(0), vector.at(1), vector.at(2)], [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn malformed_input_is_rejected() {
+        let parse = |text: &str| text.parse::<Matrix<f64, 2, 2>>().err();
+
+        assert_eq!(
+            parse(""),
+            Some(ParseMatrixError::WrongRowCount {
+                expected: 2,
+                found: 0
+            })
+        );
+        assert_eq!(
+            parse("1 2\n3 4\n5 6"),
+            Some(ParseMatrixError::WrongRowCount {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            parse("[[1, 2], [3]]"),
+            Some(ParseMatrixError::WrongColumnCount {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            parse("[[1, 2, 3], [3, 4]]"),
+            Some(ParseMatrixError::WrongColumnCount {
+                row: 0,
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            parse("1 x\n3 4"),
+            Some(ParseMatrixError::InvalidNumber { row: 0, col: 1 })
+        );
+        assert_eq!(
+            parse("[[1, 2], [3, 4]"),
+            Some(ParseMatrixError::UnbalancedBrackets)
+        );
+        assert_eq!(
+            parse("[[1, 2] x [3, 4]]"),
+            Some(ParseMatrixError::UnexpectedCharacter('x'))
+        );
+        assert!("1 2".parse::<Point>().is_err());
+
+        for text in [
+            "[",
+            "]",
+            "[]",
+            "[[]]",
+            "[[[1, 2]]]",
+            "[[1, 2]], [3, 4]]",
+            "; ;",
+            "é",
+        ] {
+            assert!(parse(text).is_some(), "{:?}", text);
+        }
+    }
+}
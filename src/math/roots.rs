@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+const MAX_BISECTIONS: usize = 1100;
+
+// Coefficients are in increasing degree, c0 + c1 x + c2 x² + ...
+pub fn evaluate_polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |value, &coefficient| value * x + coefficient)
+}
+
+// Sum of the magnitudes of the terms, the scale of the rounding error of the value
+fn term_magnitude(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |value, &coefficient| {
+        value * x.abs() + coefficient.abs()
+    })
+}
+
+fn bisect(coefficients: &[f64], mut low: f64, mut high: f64) -> f64 {
+    let low_positive = evaluate_polynomial(coefficients, low) > 0.0;
+
+    for _ in 0..MAX_BISECTIONS {
+        let middle = 0.5 * (low + high);
+        if middle <= low || middle >= high {
+            break;
+        }
+
+        let value = evaluate_polynomial(coefficients, middle);
+        if value == 0.0 {
+            return middle;
+        }
+        if (value > 0.0) == low_positive {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    0.5 * (low + high)
+}
+
+// Real roots in increasing order. The polynomial is monotone between the roots of its
+// derivative, so each of those intervals holds at most one sign change, which is
+// bisected. Roots of even multiplicity do not change sign and are found as critical
+// points where the value vanishes to within the tolerance relative to the magnitude of
+// the terms, and roots closer than the square root of the tolerance are merged, since
+// multiple roots are only that accurate. Only exactly zero leading coefficients are
+// dropped, so callers clear those that vanish up to rounding, and a zero polynomial has
+// no isolated roots.
+pub fn real_roots(coefficients: &[f64], tolerance: f64) -> Vec<f64> {
+    let degree = match coefficients
+        .iter()
+        .rposition(|&coefficient| coefficient != 0.0)
+    {
+        Some(degree)
+            if degree > 0
+                && coefficients
+                    .iter()
+                    .all(|coefficient| coefficient.is_finite()) =>
+        {
+            degree
+        }
+        _ => return Vec::new(),
+    };
+    let coefficients = &coefficients[..=degree];
+
+    if degree == 1 {
+        return alloc::vec![-coefficients[0] / coefficients[1]];
+    }
+
+    // Cauchy's bound on the magnitude of the roots
+    let leading = coefficients[degree];
+    let bound = 1.0
+        + coefficients[..degree]
+            .iter()
+            .fold(0.0_f64, |max, coefficient| {
+                max.max((coefficient / leading).abs())
+            });
+
+    let derivative: Vec<f64> = (1..=degree)
+        .map(|power| power as f64 * coefficients[power])
+        .collect();
+    let critical: Vec<f64> = real_roots(&derivative, tolerance)
+        .into_iter()
+        .filter(|point| point.abs() < bound)
+        .collect();
+
+    let mut ends = Vec::with_capacity(critical.len() + 2);
+    ends.push(-bound);
+    ends.extend_from_slice(&critical);
+    ends.push(bound);
+
+    let mut roots = Vec::new();
+    for pair in ends.windows(2) {
+        let (low, high) = (pair[0], pair[1]);
+        let (low_value, high_value) = (
+            evaluate_polynomial(coefficients, low),
+            evaluate_polynomial(coefficients, high),
+        );
+        if low_value == 0.0 {
+            roots.push(low);
+        } else if high_value != 0.0 && (low_value > 0.0) != (high_value > 0.0) {
+            roots.push(bisect(coefficients, low, high));
+        }
+    }
+
+    for &point in &critical {
+        let value = evaluate_polynomial(coefficients, point);
+        if value.abs() <= tolerance * term_magnitude(coefficients, point) {
+            roots.push(point);
+        }
+    }
+
+    roots.sort_by(f64::total_cmp);
+    let separation = tolerance.sqrt();
+    roots.dedup_by(|next, previous| {
+        (*next - *previous).abs() <= separation * (1.0 + previous.abs())
+    });
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f64 = 1e-12;
+
+    fn assert_roots(coefficients: &[f64], expected: &[f64], accuracy: f64) {
+        let roots = real_roots(coefficients, TOLERANCE);
+
+        assert_eq!(roots.len(), expected.len(), "{:?}", roots);
+        for (root, expected) in roots.iter().zip(expected) {
+            assert!((root - expected).abs() <= accuracy, "{:?}", roots);
+        }
+    }
+
+    #[test]
+    fn evaluates_in_increasing_degree() {
+        assert_eq!(evaluate_polynomial(&[1.0, -2.0, 3.0], 2.0), 9.0);
+        assert_eq!(evaluate_polynomial(&[], 2.0), 0.0);
+    }
+
+    #[test]
+    fn finds_simple_roots_in_order() {
+        // (x + 3)(x - 1)(x - 2)
+        assert_roots(&[6.0, -7.0, 0.0, 1.0], &[-3.0, 1.0, 2.0], 1e-12);
+        // (x² - 1)(x² - 4)
+        assert_roots(&[4.0, 0.0, -5.0, 0.0, 1.0], &[-2.0, -1.0, 1.0, 2.0], 1e-12);
+        assert_roots(&[-1.0, 2.0], &[0.5], 0.0);
+    }
+
+    #[test]
+    fn finds_multiple_roots_once() {
+        // (x + 2)(x - 1)²
+        assert_roots(&[2.0, -3.0, 0.0, 1.0], &[-2.0, 1.0], 1e-6);
+        // x⁴
+        assert_roots(&[0.0, 0.0, 0.0, 0.0, 1.0], &[0.0], 1e-6);
+    }
+
+    #[test]
+    fn polynomials_without_real_roots() {
+        assert_roots(&[1.0, 0.0, 1.0], &[], 0.0);
+        assert_roots(&[1.0, 0.0, 0.0, 0.0, 1.0], &[], 0.0);
+        assert_roots(&[3.0], &[], 0.0);
+        assert_roots(&[0.0, 0.0], &[], 0.0);
+        assert_roots(&[1.0, f64::NAN, 1.0], &[], 0.0);
+    }
+
+    #[test]
+    fn drops_zero_leading_coefficients() {
+        // (x - 2)(x - 3)
+        assert_roots(&[6.0, -5.0, 1.0, 0.0, 0.0], &[2.0, 3.0], 1e-12);
+    }
+}
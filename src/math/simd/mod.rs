@@ -0,0 +1,327 @@
+use crate::math::matrix::Matrix;
+use core::any::Any;
+use num_traits::Float;
+
+pub mod scalar;
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+// Column pairs of the 2x2 minors used by the cofactor expansion of a 4x4 inverse
+const MINOR_COLUMNS: [(usize, usize); 6] = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+
+// Every row of the adjugate is a signed sum of three permuted columns scaled by
+// the minors, given here as (column, minor, negate)
+const ADJUGATE_TERMS: [[(usize, usize, bool); 3]; 4] = [
+    [(1, 5, false), (2, 4, true), (3, 3, false)],
+    [(0, 5, true), (2, 2, false), (3, 1, true)],
+    [(0, 4, false), (1, 2, true), (3, 0, false)],
+    [(0, 3, true), (1, 1, false), (2, 0, true)],
+];
+
+fn minors<T: Float>(data: &[T]) -> ([T; 6], [T; 6]) {
+    let mut upper = [T::from(0.0).unwrap(); 6];
+    let mut lower = [T::from(0.0).unwrap(); 6];
+
+    for (k, &(i, j)) in MINOR_COLUMNS.iter().enumerate() {
+        upper[k] = data[i] * data[4 + j] - data[4 + i] * data[j];
+        lower[k] = data[8 + i] * data[12 + j] - data[12 + i] * data[8 + j];
+    }
+
+    (upper, lower)
+}
+
+fn determinant_from_minors<T: Float>(upper: &[T; 6], lower: &[T; 6]) -> T {
+    upper[0] * lower[5] - upper[1] * lower[4] + upper[2] * lower[3] + upper[3] * lower[2]
+        - upper[4] * lower[1]
+        + upper[5] * lower[0]
+}
+
+// The inverse of an affine transform is affine, so its bottom row is set exactly rather
+// than left to rounding, which points and vectors rely on
+fn keep_affine_row<T: Float>(data: &[T], result: &mut [[T; 4]; 4]) {
+    let (zero, one) = (T::from(0.0).unwrap(), T::from(1.0).unwrap());
+
+    if data[12..] == [zero, zero, zero, one] {
+        result[3] = [zero, zero, zero, one];
+    }
+}
+
+pub fn mul_4x4_f32(lhs: &Matrix<f32, 4, 4>, rhs: &Matrix<f32, 4, 4>) -> Matrix<f32, 4, 4> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        x86_64::mul_4x4_f32(lhs, rhs)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        scalar::mul_4x4(lhs, rhs)
+    }
+}
+
+pub fn mul_4x4_f64(lhs: &Matrix<f64, 4, 4>, rhs: &Matrix<f64, 4, 4>) -> Matrix<f64, 4, 4> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        x86_64::mul_4x4_f64(lhs, rhs)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        scalar::mul_4x4(lhs, rhs)
+    }
+}
+
+pub fn transform_4_f32(lhs: &Matrix<f32, 4, 4>, rhs: &Matrix<f32, 4, 1>) -> Matrix<f32, 4, 1> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        x86_64::transform_4_f32(lhs, rhs)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        scalar::transform_4(lhs, rhs)
+    }
+}
+
+pub fn transform_4_f64(lhs: &Matrix<f64, 4, 4>, rhs: &Matrix<f64, 4, 1>) -> Matrix<f64, 4, 1> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        x86_64::transform_4_f64(lhs, rhs)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        scalar::transform_4(lhs, rhs)
+    }
+}
+
+pub fn inverse_4x4_f32(matrix: &Matrix<f32, 4, 4>) -> Option<Matrix<f32, 4, 4>> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        x86_64::inverse_4x4_f32(matrix)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        scalar::inverse_4x4(matrix)
+    }
+}
+
+pub fn inverse_4x4_f64(matrix: &Matrix<f64, 4, 4>) -> Option<Matrix<f64, 4, 4>> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        x86_64::inverse_4x4_f64(matrix)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        scalar::inverse_4x4(matrix)
+    }
+}
+
+fn cast<A: Any, B: Any + Copy>(value: &A) -> Option<B> {
+    (value as &dyn Any).downcast_ref::<B>().copied()
+}
+
+macro_rules! try_kernel {
+    ($lhs:ident, $rhs:ident, $lhs_type:ty, $rhs_type:ty, $kernel:ident) => {
+        if let (Some(lhs), Some(rhs)) = (
+            ($lhs as &dyn Any).downcast_ref::<$lhs_type>(),
+            ($rhs as &dyn Any).downcast_ref::<$rhs_type>(),
+        ) {
+            return cast(&$kernel(lhs, rhs));
+        }
+    };
+}
+
+pub fn specialized_mul<T, const M: usize, const N: usize, const L: usize>(
+    lhs: &Matrix<T, M, N>,
+    rhs: &Matrix<T, N, L>,
+) -> Option<Matrix<T, M, L>>
+where
+    T: Float + 'static,
+{
+    try_kernel!(lhs, rhs, Matrix<f64, 4, 4>, Matrix<f64, 4, 4>, mul_4x4_f64);
+    try_kernel!(lhs, rhs, Matrix<f64, 4, 4>, Matrix<f64, 4, 1>, transform_4_f64);
+    try_kernel!(lhs, rhs, Matrix<f32, 4, 4>, Matrix<f32, 4, 4>, mul_4x4_f32);
+    try_kernel!(lhs, rhs, Matrix<f32, 4, 4>, Matrix<f32, 4, 1>, transform_4_f32);
+
+    None
+}
+
+pub fn specialized_inverse<T, const M: usize>(
+    matrix: &Matrix<T, M, M>,
+) -> Option<Option<Matrix<T, M, M>>>
+where
+    T: Float + 'static,
+{
+    let matrix = matrix as &dyn Any;
+
+    if let Some(matrix) = matrix.downcast_ref::<Matrix<f64, 4, 4>>() {
+        return Some(inverse_4x4_f64(matrix).and_then(|inverse| cast(&inverse)));
+    }
+
+    if let Some(matrix) = matrix.downcast_ref::<Matrix<f32, 4, 4>>() {
+        return Some(inverse_4x4_f32(matrix).and_then(|inverse| cast(&inverse)));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::affine::{
+        primitives::{Point, Vector},
+        transforms,
+    };
+    use alloc::{vec, vec::Vec};
+
+    // Deterministic entries in [-2, 2) with a boosted diagonal, so the matrices are
+    // well conditioned
+    fn matrices(count: usize) -> impl Iterator<Item = Matrix<f64, 4, 4>> {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 4.0 - 2.0
+        };
+
+        (0..count).map(move |_| {
+            let mut matrix =
+                Matrix::from_data(core::array::from_fn(|_| core::array::from_fn(|_| next())));
+            for i in 0..4 {
+                *matrix.at_mut(i, i) += 5.0;
+            }
+            matrix
+        })
+    }
+
+    fn assert_close<const N: usize>(
+        actual: &Matrix<f64, 4, N>,
+        expected: &Matrix<f64, 4, N>,
+        tolerance: f64,
+    ) {
+        for row in 0..4 {
+            for col in 0..N {
+                let (a, e) = (actual.at(row, col), expected.at(row, col));
+                assert!(
+                    (a - e).abs() <= tolerance * (1.0 + e.abs()),
+                    "entry ({}, {}): {} != {}",
+                    row,
+                    col,
+                    a,
+                    e
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn multiplication_matches_generic() {
+        let matrices: Vec<_> = matrices(16).collect();
+
+        for pair in matrices.windows(2) {
+            let expected = pair[0].generic_mul(&pair[1]);
+            assert_close(&scalar::mul_4x4(&pair[0], &pair[1]), &expected, 1e-14);
+            #[cfg(target_arch = "x86_64")]
+            assert_close(&x86_64::mul_4x4_f64(&pair[0], &pair[1]), &expected, 1e-14);
+
+            let (lhs, rhs) = (pair[0].with_type::<f32>(), pair[1].with_type::<f32>());
+            let expected = lhs.generic_mul(&rhs).with_type::<f64>();
+            assert_close(&scalar::mul_4x4(&lhs, &rhs).with_type(), &expected, 1e-5);
+            #[cfg(target_arch = "x86_64")]
+            assert_close(
+                &x86_64::mul_4x4_f32(&lhs, &rhs).with_type(),
+                &expected,
+                1e-5,
+            );
+        }
+    }
+
+    #[test]
+    fn transform_matches_generic() {
+        for matrix in matrices(16) {
+            let vector = Matrix::from_data([[1.5], [-2.0], [0.25], [1.0]]);
+            let expected = matrix.generic_mul(&vector);
+            assert_close(&scalar::transform_4(&matrix, &vector), &expected, 1e-14);
+            #[cfg(target_arch = "x86_64")]
+            assert_close(&x86_64::transform_4_f64(&matrix, &vector), &expected, 1e-14);
+
+            let (matrix, vector) = (matrix.with_type::<f32>(), vector.with_type::<f32>());
+            let expected = matrix.generic_mul(&vector).with_type::<f64>();
+            assert_close(
+                &scalar::transform_4(&matrix, &vector).with_type(),
+                &expected,
+                1e-5,
+            );
+            #[cfg(target_arch = "x86_64")]
+            assert_close(
+                &x86_64::transform_4_f32(&matrix, &vector).with_type(),
+                &expected,
+                1e-5,
+            );
+        }
+    }
+
+    #[test]
+    fn inverse_matches_generic() {
+        for matrix in matrices(16) {
+            let expected = matrix.generic_inverse().unwrap();
+            assert_close(&scalar::inverse_4x4(&matrix).unwrap(), &expected, 1e-12);
+            #[cfg(target_arch = "x86_64")]
+            assert_close(&x86_64::inverse_4x4_f64(&matrix).unwrap(), &expected, 1e-12);
+
+            let single = matrix.with_type::<f32>();
+            let expected = single.generic_inverse().unwrap().with_type::<f64>();
+            assert_close(
+                &scalar::inverse_4x4(&single).unwrap().with_type(),
+                &expected,
+                1e-4,
+            );
+            #[cfg(target_arch = "x86_64")]
+            assert_close(
+                &x86_64::inverse_4x4_f32(&single).unwrap().with_type(),
+                &expected,
+                1e-4,
+            );
+        }
+    }
+
+    #[test]
+    fn singular_matrices_have_no_inverse() {
+        let singular = Matrix::from_data([
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        assert!(scalar::inverse_4x4(&singular).is_none());
+        #[cfg(target_arch = "x86_64")]
+        assert!(x86_64::inverse_4x4_f64(&singular).is_none());
+    }
+
+    #[test]
+    fn affine_inverses_stay_affine() {
+        let transforms = [
+            transforms::scale(7.0, 7.0, 1.0),
+            transforms::rotate_x(0.3) * transforms::scale(3.0, 0.7, 11.0),
+            transforms::translate(Vector::new(0.1, -3.0, 2.5))
+                * transforms::rotate_z(1.1)
+                * transforms::scale(0.3, 0.3, 9.0),
+        ];
+
+        for transform in transforms {
+            let mut inverses = vec![scalar::inverse_4x4(&transform).unwrap()];
+            #[cfg(target_arch = "x86_64")]
+            inverses.push(x86_64::inverse_4x4_f64(&transform).unwrap());
+            inverses.push(transform.inverse().unwrap());
+
+            for inverse in inverses {
+                for col in 0..4 {
+                    assert_eq!(inverse.at(3, col), if col == 3 { 1.0 } else { 0.0 });
+                }
+
+                let point = transform * (inverse * Point::new(1.0, 2.0, 3.0));
+                for (axis, expected) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+                    assert!((point.at(axis) - expected).abs() < 1e-12);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use super::{determinant_from_minors, keep_affine_row, minors, ADJUGATE_TERMS};
+use crate::math::matrix::Matrix;
+use num_traits::Float;
+
+pub fn mul_4x4<T: Float>(lhs: &Matrix<T, 4, 4>, rhs: &Matrix<T, 4, 4>) -> Matrix<T, 4, 4> {
+    let mut result = [[T::from(0.0).unwrap(); 4]; 4];
+
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = lhs.at(i, 0) * rhs.at(0, j)
+                + lhs.at(i, 1) * rhs.at(1, j)
+                + lhs.at(i, 2) * rhs.at(2, j)
+                + lhs.at(i, 3) * rhs.at(3, j);
+        }
+    }
+
+    Matrix::from_data(result)
+}
+
+pub fn transform_4<T: Float>(lhs: &Matrix<T, 4, 4>, rhs: &Matrix<T, 4, 1>) -> Matrix<T, 4, 1> {
+    let mut result = [[T::from(0.0).unwrap(); 1]; 4];
+
+    for (i, row) in result.iter_mut().enumerate() {
+        row[0] = lhs.at(i, 0) * rhs.at(0, 0)
+            + lhs.at(i, 1) * rhs.at(1, 0)
+            + lhs.at(i, 2) * rhs.at(2, 0)
+            + lhs.at(i, 3) * rhs.at(3, 0);
+    }
+
+    Matrix::from_data(result)
+}
+
+pub fn inverse_4x4<T: Float>(matrix: &Matrix<T, 4, 4>) -> Option<Matrix<T, 4, 4>> {
+    let data = matrix.raw();
+    let (upper, lower) = minors(data);
+    let determinant = determinant_from_minors(&upper, &lower);
+
+    if determinant == T::from(0.0).unwrap() || !determinant.is_finite() {
+        return None;
+    }
+
+    let mut result = [[T::from(0.0).unwrap(); 4]; 4];
+
+    for (row, terms) in result.iter_mut().zip(ADJUGATE_TERMS.iter()) {
+        for &(col, minor, negate) in terms {
+            let permuted = [data[4 + col], -data[col], data[12 + col], -data[8 + col]];
+            let scales = [lower[minor], lower[minor], upper[minor], upper[minor]];
+
+            for lane in 0..4 {
+                let term = permuted[lane] * scales[lane];
+                row[lane] = if negate {
+                    row[lane] - term
+                } else {
+                    row[lane] + term
+                };
+            }
+        }
+
+        for value in row.iter_mut() {
+            *value = *value / determinant;
+        }
+    }
+
+    keep_affine_row(data, &mut result);
+    Some(Matrix::from_data(result))
+}
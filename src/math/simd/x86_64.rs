@@ -0,0 +1,208 @@
+// SSE and SSE2 are part of the x86_64 baseline, so no runtime detection is needed
+use super::{determinant_from_minors, keep_affine_row, minors, ADJUGATE_TERMS};
+use crate::math::matrix::Matrix;
+use core::arch::x86_64::*;
+
+unsafe fn load_rows_f32(data: &[f32]) -> [__m128; 4] {
+    let ptr = data.as_ptr();
+    [
+        _mm_loadu_ps(ptr),
+        _mm_loadu_ps(ptr.add(4)),
+        _mm_loadu_ps(ptr.add(8)),
+        _mm_loadu_ps(ptr.add(12)),
+    ]
+}
+
+unsafe fn load_rows_f64(data: &[f64]) -> [[__m128d; 2]; 4] {
+    let ptr = data.as_ptr();
+    [
+        [_mm_loadu_pd(ptr), _mm_loadu_pd(ptr.add(2))],
+        [_mm_loadu_pd(ptr.add(4)), _mm_loadu_pd(ptr.add(6))],
+        [_mm_loadu_pd(ptr.add(8)), _mm_loadu_pd(ptr.add(10))],
+        [_mm_loadu_pd(ptr.add(12)), _mm_loadu_pd(ptr.add(14))],
+    ]
+}
+
+pub fn mul_4x4_f32(lhs: &Matrix<f32, 4, 4>, rhs: &Matrix<f32, 4, 4>) -> Matrix<f32, 4, 4> {
+    let lhs = lhs.raw();
+    let mut result = [[0.0; 4]; 4];
+
+    unsafe {
+        let rhs_rows = load_rows_f32(rhs.raw());
+
+        for (i, row) in result.iter_mut().enumerate() {
+            let mut sum = _mm_setzero_ps();
+
+            for (k, &rhs_row) in rhs_rows.iter().enumerate() {
+                sum = _mm_add_ps(sum, _mm_mul_ps(_mm_set1_ps(lhs[4 * i + k]), rhs_row));
+            }
+
+            _mm_storeu_ps(row.as_mut_ptr(), sum);
+        }
+    }
+
+    Matrix::from_data(result)
+}
+
+pub fn mul_4x4_f64(lhs: &Matrix<f64, 4, 4>, rhs: &Matrix<f64, 4, 4>) -> Matrix<f64, 4, 4> {
+    let lhs = lhs.raw();
+    let mut result = [[0.0; 4]; 4];
+
+    unsafe {
+        let rhs_rows = load_rows_f64(rhs.raw());
+
+        for (i, row) in result.iter_mut().enumerate() {
+            let mut low = _mm_setzero_pd();
+            let mut high = _mm_setzero_pd();
+
+            for (k, &[rhs_low, rhs_high]) in rhs_rows.iter().enumerate() {
+                let factor = _mm_set1_pd(lhs[4 * i + k]);
+                low = _mm_add_pd(low, _mm_mul_pd(factor, rhs_low));
+                high = _mm_add_pd(high, _mm_mul_pd(factor, rhs_high));
+            }
+
+            _mm_storeu_pd(row.as_mut_ptr(), low);
+            _mm_storeu_pd(row.as_mut_ptr().add(2), high);
+        }
+    }
+
+    Matrix::from_data(result)
+}
+
+pub fn transform_4_f32(lhs: &Matrix<f32, 4, 4>, rhs: &Matrix<f32, 4, 1>) -> Matrix<f32, 4, 1> {
+    let mut result = [0.0; 4];
+
+    unsafe {
+        let rows = load_rows_f32(lhs.raw());
+        let vector = _mm_loadu_ps(rhs.raw().as_ptr());
+        let products = rows.map(|row| _mm_mul_ps(row, vector));
+
+        // Transpose the products so that each lane holds the sum for one row
+        let low01 = _mm_unpacklo_ps(products[0], products[1]);
+        let low23 = _mm_unpacklo_ps(products[2], products[3]);
+        let high01 = _mm_unpackhi_ps(products[0], products[1]);
+        let high23 = _mm_unpackhi_ps(products[2], products[3]);
+
+        let sum = _mm_add_ps(
+            _mm_add_ps(_mm_movelh_ps(low01, low23), _mm_movehl_ps(low23, low01)),
+            _mm_add_ps(_mm_movelh_ps(high01, high23), _mm_movehl_ps(high23, high01)),
+        );
+
+        _mm_storeu_ps(result.as_mut_ptr(), sum);
+    }
+
+    Matrix::from_data(result.map(|value| [value]))
+}
+
+pub fn transform_4_f64(lhs: &Matrix<f64, 4, 4>, rhs: &Matrix<f64, 4, 1>) -> Matrix<f64, 4, 1> {
+    let mut result = [0.0; 4];
+
+    unsafe {
+        let rows = load_rows_f64(lhs.raw());
+        let vector_ptr = rhs.raw().as_ptr();
+        let vector_low = _mm_loadu_pd(vector_ptr);
+        let vector_high = _mm_loadu_pd(vector_ptr.add(2));
+
+        let products = rows.map(|[low, high]| {
+            _mm_add_pd(_mm_mul_pd(low, vector_low), _mm_mul_pd(high, vector_high))
+        });
+
+        for pair in 0..2 {
+            let first = products[2 * pair];
+            let second = products[2 * pair + 1];
+            let sum = _mm_add_pd(
+                _mm_unpacklo_pd(first, second),
+                _mm_unpackhi_pd(first, second),
+            );
+
+            _mm_storeu_pd(result.as_mut_ptr().add(2 * pair), sum);
+        }
+    }
+
+    Matrix::from_data(result.map(|value| [value]))
+}
+
+pub fn inverse_4x4_f32(matrix: &Matrix<f32, 4, 4>) -> Option<Matrix<f32, 4, 4>> {
+    let data = matrix.raw();
+    let (upper, lower) = minors(data);
+    let determinant = determinant_from_minors(&upper, &lower);
+
+    if determinant == 0.0 || !determinant.is_finite() {
+        return None;
+    }
+
+    let mut result = [[0.0; 4]; 4];
+
+    unsafe {
+        let permuted_columns: [__m128; 4] = core::array::from_fn(|col| {
+            _mm_setr_ps(data[4 + col], -data[col], data[12 + col], -data[8 + col])
+        });
+        let determinant = _mm_set1_ps(determinant);
+
+        for (row, terms) in result.iter_mut().zip(ADJUGATE_TERMS.iter()) {
+            let mut sum = _mm_setzero_ps();
+
+            for &(col, minor, negate) in terms {
+                let scales = _mm_setr_ps(lower[minor], lower[minor], upper[minor], upper[minor]);
+                let term = _mm_mul_ps(permuted_columns[col], scales);
+                sum = if negate {
+                    _mm_sub_ps(sum, term)
+                } else {
+                    _mm_add_ps(sum, term)
+                };
+            }
+
+            _mm_storeu_ps(row.as_mut_ptr(), _mm_div_ps(sum, determinant));
+        }
+    }
+
+    keep_affine_row(data, &mut result);
+    Some(Matrix::from_data(result))
+}
+
+pub fn inverse_4x4_f64(matrix: &Matrix<f64, 4, 4>) -> Option<Matrix<f64, 4, 4>> {
+    let data = matrix.raw();
+    let (upper, lower) = minors(data);
+    let determinant = determinant_from_minors(&upper, &lower);
+
+    if determinant == 0.0 || !determinant.is_finite() {
+        return None;
+    }
+
+    let mut result = [[0.0; 4]; 4];
+
+    unsafe {
+        let permuted_columns: [[__m128d; 2]; 4] = core::array::from_fn(|col| {
+            [
+                _mm_setr_pd(data[4 + col], -data[col]),
+                _mm_setr_pd(data[12 + col], -data[8 + col]),
+            ]
+        });
+        let determinant = _mm_set1_pd(determinant);
+
+        for (row, terms) in result.iter_mut().zip(ADJUGATE_TERMS.iter()) {
+            let mut low = _mm_setzero_pd();
+            let mut high = _mm_setzero_pd();
+
+            for &(col, minor, negate) in terms {
+                let [column_low, column_high] = permuted_columns[col];
+                let term_low = _mm_mul_pd(column_low, _mm_set1_pd(lower[minor]));
+                let term_high = _mm_mul_pd(column_high, _mm_set1_pd(upper[minor]));
+
+                if negate {
+                    low = _mm_sub_pd(low, term_low);
+                    high = _mm_sub_pd(high, term_high);
+                } else {
+                    low = _mm_add_pd(low, term_low);
+                    high = _mm_add_pd(high, term_high);
+                }
+            }
+
+            _mm_storeu_pd(row.as_mut_ptr(), _mm_div_pd(low, determinant));
+            _mm_storeu_pd(row.as_mut_ptr().add(2), _mm_div_pd(high, determinant));
+        }
+    }
+
+    keep_affine_row(data, &mut result);
+    Some(Matrix::from_data(result))
+}
@@ -0,0 +1,469 @@
+use crate::{
+    error::{Error, Result},
+    forms::implicit::*,
+    math::affine::primitives::{Point, Vector},
+    mesh::triangle_mesh::Mesh,
+};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// Cube corners are numbered by the bits x = 1, y = 2 and z = 4. Every cube is split
+// into the six tetrahedra around its diagonal from corner 0 to corner 7, and as all
+// cubes use the same diagonal the faces of neighbouring tetrahedra match, which keeps
+// the mesh watertight and leaves no ambiguous cases to resolve. This is marching
+// tetrahedra rather than the marching cubes case table, which needs extra rules for
+// its ambiguous faces to stay watertight, at the cost of about twice the triangles.
+const TETRAHEDRA: [[usize; 4]; 6] = [
+    [0, 1, 3, 7],
+    [0, 1, 5, 7],
+    [0, 2, 3, 7],
+    [0, 2, 6, 7],
+    [0, 4, 5, 7],
+    [0, 4, 6, 7],
+];
+
+// Extracts the surface where the form vanishes within the bounding box, sampled on a
+// grid of the given number of cells along each axis. Points with a negative value are
+// inside, and the mesh is oriented with normals pointing outside.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarchingTetrahedra {
+    pub bounds: (Point, Point),
+    pub resolution: [usize; 3],
+    // Closes the surface with the faces of the box where the inside reaches them, so
+    // that clipped and unbounded forms still give solids
+    pub capped: bool,
+    // Newton steps moving vertices onto the surface, which removes the error of the
+    // linear interpolation along the grid edges
+    pub projection_iterations: usize,
+}
+
+// Vertices are either at a grid node next to the padding or on the edge between two
+// nodes, indexed in the extended grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum VertexKey {
+    Node(usize),
+    Edge(usize, usize),
+}
+
+struct Grid {
+    min: Point,
+    cell: [f64; 3],
+    nodes: [usize; 3],
+    // Capped grids are surrounded by a layer of nodes that are outside
+    padding: usize,
+    values: Vec<f64>,
+}
+
+impl Grid {
+    fn extent(&self, axis: usize) -> usize {
+        self.nodes[axis] + 2 * self.padding
+    }
+
+    fn index(&self, node: [usize; 3]) -> usize {
+        (node[2] * self.extent(1) + node[1]) * self.extent(0) + node[0]
+    }
+
+    fn node(&self, index: usize) -> [usize; 3] {
+        let (width, height) = (self.extent(0), self.extent(1));
+        [
+            index % width,
+            (index / width) % height,
+            index / (width * height),
+        ]
+    }
+
+    // Coordinates in the sampled grid, None for padding nodes
+    fn sampled(&self, node: [usize; 3]) -> Option<[usize; 3]> {
+        let mut sampled = [0; 3];
+
+        for axis in 0..3 {
+            sampled[axis] = node[axis].checked_sub(self.padding)?;
+            if sampled[axis] >= self.nodes[axis] {
+                return None;
+            }
+        }
+
+        Some(sampled)
+    }
+
+    fn value(&self, index: usize) -> f64 {
+        match self.sampled(self.node(index)) {
+            Some([i, j, k]) => self.values[(k * self.nodes[1] + j) * self.nodes[0] + i],
+            None => f64::INFINITY,
+        }
+    }
+
+    fn position(&self, index: usize) -> Point {
+        let node = self.node(index);
+
+        Point::new(
+            self.min.at(0) + (node[0] as f64 - self.padding as f64) * self.cell[0],
+            self.min.at(1) + (node[1] as f64 - self.padding as f64) * self.cell[1],
+            self.min.at(2) + (node[2] as f64 - self.padding as f64) * self.cell[2],
+        )
+    }
+
+    // Outward normal of the box faces a sampled node lies on, zero for interior nodes
+    fn boundary_normal(&self, index: usize) -> Vector {
+        let mut normal = [0.0; 3];
+
+        if let Some(sampled) = self.sampled(self.node(index)) {
+            for axis in 0..3 {
+                if sampled[axis] == 0 {
+                    normal[axis] -= 1.0;
+                }
+                if sampled[axis] + 1 == self.nodes[axis] {
+                    normal[axis] += 1.0;
+                }
+            }
+        }
+
+        Vector::new(normal[0], normal[1], normal[2])
+    }
+}
+
+struct Builder<'a> {
+    grid: &'a Grid,
+    keys: BTreeMap<VertexKey, usize>,
+    vertices: Vec<(VertexKey, Point)>,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl Builder<'_> {
+    // The zero crossing on the edge from an inside node to an outside node
+    fn crossing(&mut self, inside: usize, outside: usize) -> usize {
+        let (inside_value, outside_value) = (self.grid.value(inside), self.grid.value(outside));
+        let t = inside_value / (inside_value - outside_value);
+
+        // Crossings with the padding are all at the inside node, which closes the caps.
+        // Other crossings stay on their edge even when they reach a node with a zero
+        // value, as merging them there could pinch the surface.
+        let key = if outside_value.is_infinite() {
+            VertexKey::Node(inside)
+        } else {
+            VertexKey::Edge(inside.min(outside), inside.max(outside))
+        };
+
+        if let Some(&vertex) = self.keys.get(&key) {
+            return vertex;
+        }
+
+        let start = self.grid.position(inside);
+        let point = match key {
+            VertexKey::Node(node) => self.grid.position(node),
+            VertexKey::Edge(..) => start + (self.grid.position(outside) - start) * t,
+        };
+
+        self.vertices.push((key, point));
+        self.keys.insert(key, self.vertices.len() - 1);
+        self.vertices.len() - 1
+    }
+
+    // Adds the triangle through the crossings on the given edges, facing from the inside
+    // nodes towards the outside nodes. Crossings can coincide where the form vanishes
+    // at a node, so the orientation is taken from the midpoints of the edges, which
+    // never do.
+    fn triangle(&mut self, edges: [(usize, usize); 3], direction: Vector) {
+        let [a, b, c] = edges.map(|(inside, outside)| self.crossing(inside, outside));
+        if a == b || b == c || c == a {
+            return;
+        }
+
+        let [pa, pb, pc] = edges.map(|(inside, outside)| {
+            let start = self.grid.position(inside);
+            start + (self.grid.position(outside) - start) * 0.5
+        });
+        if (pb - pa).cross(pc - pa) * direction >= 0.0 {
+            self.triangles.push([a, b, c]);
+        } else {
+            self.triangles.push([a, c, b]);
+        }
+    }
+
+    fn tetrahedron(&mut self, corners: [usize; 4]) {
+        let (mut inside, mut outside) = (Vec::with_capacity(4), Vec::with_capacity(4));
+        for corner in corners {
+            if self.grid.value(corner) < 0.0 {
+                inside.push(corner);
+            } else {
+                outside.push(corner);
+            }
+        }
+
+        if inside.is_empty() || outside.is_empty() {
+            return;
+        }
+
+        let centroid = |nodes: &[usize]| {
+            nodes
+                .iter()
+                .map(|&node| Vector::to_point(self.grid.position(node)))
+                .fold(Vector::new(0.0, 0.0, 0.0), |sum, position| sum + position)
+                * (1.0 / nodes.len() as f64)
+        };
+        let direction = centroid(&outside) - centroid(&inside);
+
+        match (inside.len(), outside.len()) {
+            (1, 3) => {
+                let edges = [0, 1, 2].map(|i| (inside[0], outside[i]));
+                self.triangle(edges, direction);
+            }
+            (3, 1) => {
+                let edges = [0, 1, 2].map(|i| (inside[i], outside[0]));
+                self.triangle(edges, direction);
+            }
+            _ => {
+                // The crossings form a quadrilateral in this cyclic order
+                let a = (inside[0], outside[0]);
+                let b = (inside[0], outside[1]);
+                let c = (inside[1], outside[1]);
+                let d = (inside[1], outside[0]);
+
+                self.triangle([a, b, c], direction);
+                self.triangle([a, c, d], direction);
+            }
+        }
+    }
+}
+
+impl MarchingTetrahedra {
+    pub fn new(bounds: (Point, Point), resolution: [usize; 3]) -> MarchingTetrahedra {
+        MarchingTetrahedra {
+            bounds,
+            resolution,
+            capped: true,
+            projection_iterations: 0,
+        }
+    }
+
+    // Cubic cells of about the given size
+    pub fn with_cell_size(bounds: (Point, Point), cell_size: f64) -> Result<MarchingTetrahedra> {
+        if !(cell_size > 0.0 && cell_size.is_finite()) {
+            return Err(Error::NotPositive { value: cell_size });
+        }
+
+        let (min, max) = bounds;
+        let resolution = core::array::from_fn(|axis| {
+            ((max.at(axis) - min.at(axis)) / cell_size).ceil().max(1.0) as usize
+        });
+
+        Ok(Self::new(bounds, resolution))
+    }
+
+    fn sample<F: ImplicitForm + ?Sized>(&self, form: &F) -> Grid {
+        let (min, max) = self.bounds;
+        let resolution = self.resolution.map(|cells| cells.max(1));
+        let nodes = resolution.map(|cells| cells + 1);
+        let cell: [f64; 3] =
+            core::array::from_fn(|axis| (max.at(axis) - min.at(axis)) / resolution[axis] as f64);
+
+        let mut values = vec![0.0; nodes[0] * nodes[1] * nodes[2]];
+        for k in 0..nodes[2] {
+            for j in 0..nodes[1] {
+                for i in 0..nodes[0] {
+                    let point = Point::new(
+                        min.at(0) + i as f64 * cell[0],
+                        min.at(1) + j as f64 * cell[1],
+                        min.at(2) + k as f64 * cell[2],
+                    );
+                    values[(k * nodes[1] + j) * nodes[0] + i] = form.implicit_form_value(point);
+                }
+            }
+        }
+
+        Grid {
+            min,
+            cell,
+            nodes,
+            padding: if self.capped { 1 } else { 0 },
+            values,
+        }
+    }
+
+    pub fn extract<F: ImplicitForm + DifferentialForm + ?Sized>(&self, form: &F) -> Mesh {
+        let grid = self.sample(form);
+        let mut builder = Builder {
+            grid: &grid,
+            keys: BTreeMap::new(),
+            vertices: Vec::new(),
+            triangles: Vec::new(),
+        };
+
+        for k in 0..grid.extent(2) - 1 {
+            for j in 0..grid.extent(1) - 1 {
+                for i in 0..grid.extent(0) - 1 {
+                    let corners: [usize; 8] = core::array::from_fn(|corner| {
+                        grid.index([i + (corner & 1), j + ((corner >> 1) & 1), k + (corner >> 2)])
+                    });
+
+                    for tetrahedron in TETRAHEDRA {
+                        builder.tetrahedron(tetrahedron.map(|corner| corners[corner]));
+                    }
+                }
+            }
+        }
+
+        let Builder {
+            vertices,
+            triangles,
+            ..
+        } = builder;
+
+        // Vertices on the faces of the box keep their place so that the caps stay flat,
+        // and the node vertices inside the caps take the normal of the box
+        let on_faces = |key: VertexKey| {
+            let (first, second) = match key {
+                VertexKey::Node(node) => (node, node),
+                VertexKey::Edge(first, second) => (first, second),
+            };
+            let (first, second) = (grid.boundary_normal(first), grid.boundary_normal(second));
+
+            grid.padding > 0
+                && (0..3).any(|axis| first.at(axis) != 0.0 && first.at(axis) == second.at(axis))
+        };
+
+        let points: Vec<Point> = vertices
+            .iter()
+            .map(|&(key, point)| {
+                if on_faces(key) {
+                    point
+                } else {
+                    self.project(form, point)
+                }
+            })
+            .collect();
+
+        // Critical points of the form have no gradient, so their normal is averaged
+        // from the faces around them
+        let mut face_normals = vec![Vector::new(0.0, 0.0, 0.0); points.len()];
+        for triangle in &triangles {
+            let [a, b, c] = triangle.map(|vertex| points[vertex]);
+            for &vertex in triangle {
+                face_normals[vertex] = face_normals[vertex] + (b - a).cross(c - a);
+            }
+        }
+
+        let normals = vertices
+            .iter()
+            .zip(&points)
+            .zip(face_normals)
+            .map(|((&(key, _), &point), face_normal)| {
+                let normal = match key {
+                    VertexKey::Node(node) => grid.boundary_normal(node),
+                    VertexKey::Edge(..) => form.derivative_at(point),
+                };
+
+                if normal.length() > 0.0 {
+                    normal.normalized()
+                } else if face_normal.length() > 0.0 {
+                    face_normal.normalized()
+                } else {
+                    normal
+                }
+            })
+            .collect();
+
+        Mesh::new(points, normals, triangles)
+    }
+
+    // Newton steps along the gradient, stopping at steps longer than about a cell where
+    // the gradient is too small to be trusted
+    fn project<F: ImplicitForm + DifferentialForm + ?Sized>(
+        &self,
+        form: &F,
+        point: Point,
+    ) -> Point {
+        let (min, max) = self.bounds;
+        let limit =
+            (max - min).length() / self.resolution.iter().max().copied().unwrap_or(1).max(1) as f64;
+        let mut point = point;
+
+        for _ in 0..self.projection_iterations {
+            let gradient = form.derivative_at(point);
+            let length_squared = gradient * gradient;
+            if length_squared == 0.0 {
+                break;
+            }
+
+            let step = gradient * (-form.implicit_form_value(point) / length_squared);
+            if step.length() > limit {
+                break;
+            }
+
+            point = point + step;
+        }
+
+        point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::{cylinder::EllipticCylinder, sphere::Sphere};
+
+    fn cube(half_size: f64) -> (Point, Point) {
+        (
+            Point::new(-half_size, -half_size, -half_size),
+            Point::new(half_size, half_size, half_size),
+        )
+    }
+
+    #[test]
+    fn spheres_give_closed_outward_meshes() {
+        let mut mesher = MarchingTetrahedra::new(cube(1.5), [20, 20, 20]);
+        mesher.projection_iterations = 4;
+        let mesh = mesher.extract(&Sphere::with_radius(1.0));
+
+        assert!(mesh.is_watertight());
+        assert!((mesh.volume() - 4.0 / 3.0 * core::f64::consts::PI).abs() < 0.05);
+        for (&vertex, &normal) in mesh.vertices().iter().zip(mesh.normals()) {
+            let radial = Vector::to_point(vertex);
+            assert!((radial.length() - 1.0).abs() < 1e-9);
+            assert!((normal - radial.normalized()).length() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn caps_close_unbounded_forms() {
+        let bounds = (Point::new(-2.0, -2.0, -1.0), Point::new(2.0, 2.0, 1.0));
+        let cylinder = EllipticCylinder::with_radii(1.0, 1.0);
+
+        let capped = MarchingTetrahedra::with_cell_size(bounds, 0.1)
+            .unwrap()
+            .extract(&cylinder);
+        assert!(capped.is_watertight());
+        assert!((capped.volume() - 2.0 * core::f64::consts::PI).abs() < 0.1);
+
+        let mut mesher = MarchingTetrahedra::with_cell_size(bounds, 0.1).unwrap();
+        mesher.capped = false;
+        let open = mesher.extract(&cylinder);
+        assert!(!open.is_empty() && !open.is_watertight());
+    }
+
+    #[test]
+    fn boxes_inside_the_form_keep_only_their_caps() {
+        let mesh = MarchingTetrahedra::new(cube(0.5), [4, 4, 4]).extract(&Sphere::with_radius(2.0));
+
+        // The box is inside the sphere, so only the caps remain
+        assert!(mesh.is_watertight());
+        assert!((mesh.volume() - 1.0).abs() < 1e-12);
+
+        let mut mesher = MarchingTetrahedra::new(cube(0.5), [4, 4, 4]);
+        mesher.capped = false;
+        assert!(mesher.extract(&Sphere::with_radius(2.0)).is_empty());
+    }
+
+    #[test]
+    fn cell_sizes_must_be_positive() {
+        for cell_size in [0.0, -0.1, f64::NAN, f64::INFINITY] {
+            let error = MarchingTetrahedra::with_cell_size(cube(1.0), cell_size).unwrap_err();
+            assert!(matches!(error, Error::NotPositive { .. }));
+        }
+
+        let mesher = MarchingTetrahedra::with_cell_size(cube(1.0), 0.3).unwrap();
+        assert_eq!(mesher.resolution, [7, 7, 7]);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod marching_tetrahedra;
+#[cfg(feature = "std")]
+pub mod obj;
+#[cfg(feature = "std")]
+pub mod ply;
+#[cfg(feature = "std")]
+pub mod stl;
+pub mod triangle_mesh;
@@ -0,0 +1,257 @@
+use crate::{
+    error::{Error, Result},
+    math::affine::primitives::{Point, Vector},
+    mesh::triangle_mesh::Mesh,
+    primitives::color::Color,
+};
+use std::{
+    collections::BTreeMap,
+    io::{BufRead, Write},
+};
+
+// Wavefront OBJ with one position, texture coordinate and normal index per vertex.
+// Colours use the common extension of three more components on the vertex lines.
+pub fn write_obj<W: Write>(mesh: &Mesh, mut writer: W) -> Result<()> {
+    for (index, vertex) in mesh.vertices().iter().enumerate() {
+        write!(
+            writer,
+            "v {} {} {}",
+            vertex.at(0),
+            vertex.at(1),
+            vertex.at(2)
+        )?;
+        if let Some(colors) = mesh.colors() {
+            let color = colors[index];
+            write!(writer, " {} {} {}", color.r, color.g, color.b)?;
+        }
+        writeln!(writer)?;
+    }
+
+    if let Some(uvs) = mesh.uvs() {
+        for [u, v] in uvs {
+            writeln!(writer, "vt {} {}", u, v)?;
+        }
+    }
+
+    for normal in mesh.normals() {
+        writeln!(
+            writer,
+            "vn {} {} {}",
+            normal.at(0),
+            normal.at(1),
+            normal.at(2)
+        )?;
+    }
+
+    for triangle in mesh.triangles() {
+        write!(writer, "f")?;
+        for index in triangle.map(|index| index + 1) {
+            if mesh.uvs().is_some() {
+                write!(writer, " {}/{}/{}", index, index, index)?;
+            } else {
+                write!(writer, " {}//{}", index, index)?;
+            }
+        }
+        writeln!(writer)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+fn parse_numbers<'a>(fields: impl Iterator<Item = &'a str>, line: usize) -> Result<Vec<f64>> {
+    fields
+        .map(|field| {
+            field
+                .parse()
+                .map_err(|_| Error::InvalidMesh(format!("line {}: invalid number {}", line, field)))
+        })
+        .collect()
+}
+
+// One based and negative indices relative to the end, as of the current line
+fn resolve_index(field: &str, count: usize, line: usize) -> Result<usize> {
+    let invalid = || Error::InvalidMesh(format!("line {}: invalid index {}", line, field));
+    let index: i64 = field.parse().map_err(|_| invalid())?;
+
+    let resolved = if index > 0 {
+        index - 1
+    } else {
+        count as i64 + index
+    };
+    if index == 0 || resolved < 0 || resolved >= count as i64 {
+        return Err(invalid());
+    }
+
+    Ok(resolved as usize)
+}
+
+// Reads positions, texture coordinates, normals and faces, which are split into
+// triangle fans. Vertices are distinct combinations of the indices on the faces.
+// Texture coordinates are kept when all vertices have them, and normals are computed
+// from the faces unless all vertices have them.
+pub fn read_obj<R: BufRead>(reader: R) -> Result<Mesh> {
+    let (mut positions, mut colors, mut uvs, mut normals) =
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    let mut corners: BTreeMap<(usize, Option<usize>, Option<usize>), usize> = BTreeMap::new();
+    let mut keys = Vec::new();
+    let mut triangles = Vec::new();
+
+    for (number, line) in reader.lines().enumerate() {
+        let (line, number) = (line?, number + 1);
+        let mut fields = line.split_whitespace();
+
+        match fields.next() {
+            Some("v") => {
+                let values = parse_numbers(fields, number)?;
+                if values.len() < 3 {
+                    return Err(Error::InvalidMesh(format!(
+                        "line {}: vertex with {} coordinates",
+                        number,
+                        values.len()
+                    )));
+                }
+
+                positions.push(Point::new(values[0], values[1], values[2]));
+                colors.push((values.len() >= 6).then(|| Color {
+                    r: values[3] as f32,
+                    g: values[4] as f32,
+                    b: values[5] as f32,
+                    a: 1.0,
+                }));
+            }
+            Some("vt") => {
+                let values = parse_numbers(fields, number)?;
+                uvs.push([
+                    values.first().copied().unwrap_or(0.0),
+                    values.get(1).copied().unwrap_or(0.0),
+                ]);
+            }
+            Some("vn") => {
+                let values = parse_numbers(fields, number)?;
+                if values.len() < 3 {
+                    return Err(Error::InvalidMesh(format!(
+                        "line {}: normal with {} coordinates",
+                        number,
+                        values.len()
+                    )));
+                }
+
+                normals.push(Vector::new(values[0], values[1], values[2]));
+            }
+            Some("f") => {
+                let mut face = Vec::new();
+
+                for corner in fields {
+                    let mut indices = corner.split('/');
+                    let position =
+                        resolve_index(indices.next().unwrap_or(""), positions.len(), number)?;
+                    let uv = match indices.next() {
+                        Some(field) if !field.is_empty() => {
+                            Some(resolve_index(field, uvs.len(), number)?)
+                        }
+                        _ => None,
+                    };
+                    let normal = match indices.next() {
+                        Some(field) if !field.is_empty() => {
+                            Some(resolve_index(field, normals.len(), number)?)
+                        }
+                        _ => None,
+                    };
+
+                    let key = (position, uv, normal);
+                    let vertex = *corners.entry(key).or_insert_with(|| {
+                        keys.push(key);
+                        keys.len() - 1
+                    });
+                    face.push(vertex);
+                }
+
+                if face.len() < 3 {
+                    return Err(Error::InvalidMesh(format!(
+                        "line {}: face with {} vertices",
+                        number,
+                        face.len()
+                    )));
+                }
+
+                for i in 1..face.len() - 1 {
+                    triangles.push([face[0], face[i], face[i + 1]]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let vertices = keys
+        .iter()
+        .map(|&(position, _, _)| positions[position])
+        .collect();
+    let mesh = if keys.iter().all(|&(_, _, normal)| normal.is_some()) {
+        Mesh::try_new(
+            vertices,
+            keys.iter()
+                .map(|&(_, _, normal)| normals[normal.unwrap_or(0)])
+                .collect(),
+            triangles,
+        )?
+    } else {
+        Mesh::with_face_normals(vertices, triangles)?
+    };
+
+    let mesh = if !keys.is_empty() && keys.iter().all(|&(_, uv, _)| uv.is_some()) {
+        mesh.with_uvs(
+            keys.iter()
+                .map(|&(_, uv, _)| uvs[uv.unwrap_or(0)])
+                .collect(),
+        )?
+    } else {
+        mesh
+    };
+
+    if !keys.is_empty()
+        && keys
+            .iter()
+            .all(|&(position, _, _)| colors[position].is_some())
+    {
+        let colors = keys
+            .iter()
+            .filter_map(|&(position, _, _)| colors[position])
+            .collect();
+        mesh.with_colors(colors)
+    } else {
+        Ok(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::triangle_mesh::tests::*;
+
+    fn round_trip(mesh: &Mesh) -> Mesh {
+        let mut bytes = Vec::new();
+        write_obj(mesh, &mut bytes).unwrap();
+        read_obj(bytes.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn round_trips() {
+        let mesh = tetrahedron();
+        assert_same(&round_trip(&mesh), &mesh, 0.0);
+
+        let mesh = decorated_tetrahedron();
+        assert_same(&round_trip(&mesh), &mesh, 0.0);
+    }
+
+    #[test]
+    fn rejects_invalid_indices() {
+        for face in ["f 1 2 4", "f 0 1 2", "f -4 1 2", "f 1 2 x", "f 1 2"] {
+            let text = format!("v 0 0 0\nv 1 0 0\nv 0 1 0\n{}\n", face);
+            assert!(matches!(
+                read_obj(text.as_bytes()),
+                Err(Error::InvalidMesh(_))
+            ));
+        }
+    }
+}
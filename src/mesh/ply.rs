@@ -0,0 +1,492 @@
+use crate::{
+    error::{Error, Result},
+    math::affine::primitives::{Point, Vector},
+    mesh::triangle_mesh::Mesh,
+    primitives::color::Color,
+};
+use std::io::{BufRead, Write};
+
+fn invalid(message: String) -> Error {
+    Error::InvalidMesh(message)
+}
+
+// Counts and indices may be stored as any scalar type, including floats
+fn to_index(value: f64, what: &str) -> Result<usize> {
+    if value >= 0.0 && value.fract() == 0.0 && value <= u32::MAX as f64 {
+        Ok(value as usize)
+    } else {
+        Err(invalid(format!("invalid {} {}", what, value)))
+    }
+}
+
+fn write_header<W: Write>(mesh: &Mesh, format: &str, writer: &mut W) -> Result<()> {
+    writeln!(writer, "ply")?;
+    writeln!(writer, "format {} 1.0", format)?;
+    writeln!(writer, "element vertex {}", mesh.vertices().len())?;
+    for axis in ["x", "y", "z"] {
+        writeln!(writer, "property double {}", axis)?;
+    }
+    for axis in ["nx", "ny", "nz"] {
+        writeln!(writer, "property float {}", axis)?;
+    }
+    if mesh.uvs().is_some() {
+        writeln!(writer, "property float s")?;
+        writeln!(writer, "property float t")?;
+    }
+    if mesh.colors().is_some() {
+        for channel in ["red", "green", "blue", "alpha"] {
+            writeln!(writer, "property uchar {}", channel)?;
+        }
+    }
+    writeln!(writer, "element face {}", mesh.triangles().len())?;
+    writeln!(writer, "property list uchar uint vertex_indices")?;
+    writeln!(writer, "end_header")?;
+
+    Ok(())
+}
+
+fn color_bytes(color: Color) -> [u8; 4] {
+    [color.r, color.g, color.b, color.a]
+        .map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+pub fn write_ply_ascii<W: Write>(mesh: &Mesh, mut writer: W) -> Result<()> {
+    write_header(mesh, "ascii", &mut writer)?;
+
+    for (index, (vertex, normal)) in mesh.vertices().iter().zip(mesh.normals()).enumerate() {
+        write!(
+            writer,
+            "{} {} {} {} {} {}",
+            vertex.at(0),
+            vertex.at(1),
+            vertex.at(2),
+            normal.at(0) as f32,
+            normal.at(1) as f32,
+            normal.at(2) as f32
+        )?;
+        if let Some(uvs) = mesh.uvs() {
+            write!(writer, " {} {}", uvs[index][0] as f32, uvs[index][1] as f32)?;
+        }
+        if let Some(colors) = mesh.colors() {
+            let [r, g, b, a] = color_bytes(colors[index]);
+            write!(writer, " {} {} {} {}", r, g, b, a)?;
+        }
+        writeln!(writer)?;
+    }
+
+    for [a, b, c] in mesh.triangles() {
+        writeln!(writer, "3 {} {} {}", a, b, c)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write_ply_binary<W: Write>(mesh: &Mesh, mut writer: W) -> Result<()> {
+    write_header(mesh, "binary_little_endian", &mut writer)?;
+
+    for (index, (vertex, normal)) in mesh.vertices().iter().zip(mesh.normals()).enumerate() {
+        for axis in 0..3 {
+            writer.write_all(&vertex.at(axis).to_le_bytes())?;
+        }
+        for axis in 0..3 {
+            writer.write_all(&(normal.at(axis) as f32).to_le_bytes())?;
+        }
+        if let Some(uvs) = mesh.uvs() {
+            for coordinate in uvs[index] {
+                writer.write_all(&(coordinate as f32).to_le_bytes())?;
+            }
+        }
+        if let Some(colors) = mesh.colors() {
+            writer.write_all(&color_bytes(colors[index]))?;
+        }
+    }
+
+    for triangle in mesh.triangles() {
+        writer.write_all(&[3])?;
+        for index in triangle {
+            let index = u32::try_from(*index)
+                .map_err(|_| invalid(format!("vertex index {} does not fit in PLY", index)))?;
+            writer.write_all(&index.to_le_bytes())?;
+        }
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Encoding {
+    Ascii,
+    LittleEndian,
+    BigEndian,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Scalar {
+    Int8,
+    UInt8,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Float32,
+    Float64,
+}
+
+impl Scalar {
+    fn parse(name: &str) -> Result<Scalar> {
+        Ok(match name {
+            "char" | "int8" => Scalar::Int8,
+            "uchar" | "uint8" => Scalar::UInt8,
+            "short" | "int16" => Scalar::Int16,
+            "ushort" | "uint16" => Scalar::UInt16,
+            "int" | "int32" => Scalar::Int32,
+            "uint" | "uint32" => Scalar::UInt32,
+            "float" | "float32" => Scalar::Float32,
+            "double" | "float64" => Scalar::Float64,
+            _ => return Err(invalid(format!("unknown property type {}", name))),
+        })
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Scalar::Int8 | Scalar::UInt8 => 1,
+            Scalar::Int16 | Scalar::UInt16 => 2,
+            Scalar::Int32 | Scalar::UInt32 | Scalar::Float32 => 4,
+            Scalar::Float64 => 8,
+        }
+    }
+
+    // Colour channels stored as integers are scaled to [0, 1]
+    fn channel_scale(&self) -> f64 {
+        match self {
+            Scalar::UInt8 => 1.0 / 255.0,
+            Scalar::UInt16 => 1.0 / 65535.0,
+            _ => 1.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Property {
+    name: String,
+    // The type of the length for list properties
+    list: Option<Scalar>,
+    scalar: Scalar,
+}
+
+#[derive(Clone, Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+// Values of the body in order, either as text or as binary
+struct Body<'a> {
+    encoding: Encoding,
+    bytes: &'a [u8],
+    position: usize,
+    tokens: core::str::SplitAsciiWhitespace<'a>,
+}
+
+impl Body<'_> {
+    fn next(&mut self, scalar: Scalar) -> Result<f64> {
+        if self.encoding == Encoding::Ascii {
+            let token = self
+                .tokens
+                .next()
+                .ok_or_else(|| invalid(String::from("unexpected end of data")))?;
+            return token
+                .parse()
+                .map_err(|_| invalid(format!("invalid number {}", token)));
+        }
+
+        let size = scalar.size();
+        let bytes = self
+            .bytes
+            .get(self.position..self.position + size)
+            .ok_or_else(|| invalid(String::from("unexpected end of data")))?;
+        self.position += size;
+
+        let mut buffer = [0; 8];
+        buffer[..size].copy_from_slice(bytes);
+        if self.encoding == Encoding::BigEndian {
+            buffer[..size].reverse();
+        }
+
+        let [b0, b1, b2, b3, ..] = buffer;
+        Ok(match scalar {
+            Scalar::Int8 => b0 as i8 as f64,
+            Scalar::UInt8 => b0 as f64,
+            Scalar::Int16 => i16::from_le_bytes([b0, b1]) as f64,
+            Scalar::UInt16 => u16::from_le_bytes([b0, b1]) as f64,
+            Scalar::Int32 => i32::from_le_bytes([b0, b1, b2, b3]) as f64,
+            Scalar::UInt32 => u32::from_le_bytes([b0, b1, b2, b3]) as f64,
+            Scalar::Float32 => f32::from_le_bytes([b0, b1, b2, b3]) as f64,
+            Scalar::Float64 => f64::from_le_bytes(buffer),
+        })
+    }
+}
+
+fn read_header<R: BufRead>(reader: &mut R) -> Result<(Encoding, Vec<Element>)> {
+    let mut line = String::new();
+    let mut next_line = |reader: &mut R| -> Result<String> {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(invalid(String::from("unexpected end of header")));
+        }
+        Ok(line.trim().to_string())
+    };
+
+    if next_line(reader)? != "ply" {
+        return Err(invalid(String::from("missing ply signature")));
+    }
+
+    let mut encoding = None;
+    let mut elements: Vec<Element> = Vec::new();
+    loop {
+        let line = next_line(reader)?;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        match fields.as_slice() {
+            ["end_header"] => break,
+            ["format", format, _] => {
+                encoding = Some(match *format {
+                    "ascii" => Encoding::Ascii,
+                    "binary_little_endian" => Encoding::LittleEndian,
+                    "binary_big_endian" => Encoding::BigEndian,
+                    _ => return Err(invalid(format!("unknown format {}", format))),
+                })
+            }
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count
+                    .parse()
+                    .map_err(|_| invalid(format!("invalid element count {}", count)))?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count, scalar, name] => elements
+                .last_mut()
+                .ok_or_else(|| invalid(String::from("property before any element")))?
+                .properties
+                .push(Property {
+                    name: name.to_string(),
+                    list: Some(Scalar::parse(count)?),
+                    scalar: Scalar::parse(scalar)?,
+                }),
+            ["property", scalar, name] => elements
+                .last_mut()
+                .ok_or_else(|| invalid(String::from("property before any element")))?
+                .properties
+                .push(Property {
+                    name: name.to_string(),
+                    list: None,
+                    scalar: Scalar::parse(scalar)?,
+                }),
+            ["comment", ..] | ["obj_info", ..] | [] => {}
+            _ => return Err(invalid(format!("unexpected header line {}", line))),
+        }
+    }
+
+    let encoding = encoding.ok_or_else(|| invalid(String::from("missing format")))?;
+    Ok((encoding, elements))
+}
+
+// Reads the vertex and face elements and skips any other. Vertex normals are computed
+// from the faces when the file has none, and texture coordinates are read from s and t,
+// u and v or texture_u and texture_v.
+pub fn read_ply<R: BufRead>(mut reader: R) -> Result<Mesh> {
+    let (encoding, elements) = read_header(&mut reader)?;
+
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    let text = if encoding == Encoding::Ascii {
+        core::str::from_utf8(&data).map_err(|_| invalid(String::from("ASCII body is not text")))?
+    } else {
+        ""
+    };
+    let mut body = Body {
+        encoding,
+        bytes: &data,
+        position: 0,
+        tokens: text.split_ascii_whitespace(),
+    };
+
+    let (mut vertices, mut normals, mut uvs, mut colors) =
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    let mut triangles = Vec::new();
+    let has = |element: &Element, names: &[&str]| {
+        names.iter().all(|name| {
+            element
+                .properties
+                .iter()
+                .any(|property| property.name == *name)
+        })
+    };
+    let (mut has_normals, mut uv_names, mut has_colors) = (false, None, false);
+
+    for element in &elements {
+        if element.name == "vertex" {
+            has_normals = has(element, &["nx", "ny", "nz"]);
+            uv_names = [["s", "t"], ["u", "v"], ["texture_u", "texture_v"]]
+                .into_iter()
+                .find(|names| has(element, names));
+            has_colors = has(element, &["red", "green", "blue"]);
+        }
+
+        for _ in 0..element.count {
+            let mut values: Vec<(&str, f64, Scalar)> = Vec::with_capacity(element.properties.len());
+            let mut face = Vec::new();
+
+            for property in &element.properties {
+                match property.list {
+                    Some(count) => {
+                        let count = to_index(body.next(count)?, "list length")?;
+                        let items = (0..count)
+                            .map(|_| body.next(property.scalar))
+                            .collect::<Result<Vec<_>>>()?;
+                        if property.name == "vertex_indices" || property.name == "vertex_index" {
+                            face = items;
+                        }
+                    }
+                    None => {
+                        values.push((&property.name, body.next(property.scalar)?, property.scalar))
+                    }
+                }
+            }
+
+            let value = |name: &str| {
+                values
+                    .iter()
+                    .find(|(property, _, _)| *property == name)
+                    .map(|&(_, value, _)| value)
+                    .unwrap_or(0.0)
+            };
+            let channel = |name: &str, default: f64| {
+                values
+                    .iter()
+                    .find(|(property, _, _)| *property == name)
+                    .map(|&(_, value, scalar)| value * scalar.channel_scale())
+                    .unwrap_or(default)
+            };
+
+            match element.name.as_str() {
+                "vertex" => {
+                    vertices.push(Point::new(value("x"), value("y"), value("z")));
+                    normals.push(Vector::new(value("nx"), value("ny"), value("nz")));
+                    if let Some([u, v]) = uv_names {
+                        uvs.push([value(u), value(v)]);
+                    }
+                    colors.push(Color {
+                        r: channel("red", 0.0) as f32,
+                        g: channel("green", 0.0) as f32,
+                        b: channel("blue", 0.0) as f32,
+                        a: channel("alpha", 1.0) as f32,
+                    });
+                }
+                "face" => {
+                    if face.len() < 3 {
+                        return Err(invalid(format!("face with {} vertices", face.len())));
+                    }
+                    let face = face
+                        .iter()
+                        .map(|&index| to_index(index, "vertex index"))
+                        .collect::<Result<Vec<_>>>()?;
+                    for i in 1..face.len() - 1 {
+                        triangles.push([face[0], face[i], face[i + 1]]);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mesh = if has_normals {
+        Mesh::try_new(vertices, normals, triangles)?
+    } else {
+        Mesh::with_face_normals(vertices, triangles)?
+    };
+    let mesh = if uv_names.is_some() {
+        mesh.with_uvs(uvs)?
+    } else {
+        mesh
+    };
+
+    if has_colors {
+        mesh.with_colors(colors)
+    } else {
+        Ok(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::triangle_mesh::tests::*;
+
+    // Normals and texture coordinates are stored in single precision
+    const TOLERANCE: f64 = 1e-7;
+
+    #[test]
+    fn round_trips() {
+        for mesh in [tetrahedron(), decorated_tetrahedron()] {
+            let mut ascii = Vec::new();
+            write_ply_ascii(&mesh, &mut ascii).unwrap();
+            assert_same(&read_ply(ascii.as_slice()).unwrap(), &mesh, TOLERANCE);
+
+            let mut binary = Vec::new();
+            write_ply_binary(&mesh, &mut binary).unwrap();
+            assert_same(&read_ply(binary.as_slice()).unwrap(), &mesh, TOLERANCE);
+        }
+    }
+
+    fn with_face(face: &str, index_type: &str) -> String {
+        format!(
+            "ply\nformat ascii 1.0\nelement vertex 3\nproperty float x\nproperty float y\n\
+             property float z\nelement face 1\nproperty list uchar {} vertex_indices\n\
+             end_header\n0 0 0\n1 0 0\n0 1 0\n{}\n",
+            index_type, face
+        )
+    }
+
+    #[test]
+    fn rejects_invalid_indices() {
+        assert!(read_ply(with_face("3 0 1 2", "int").as_bytes()).is_ok());
+
+        for (face, index_type) in [
+            ("3 0 1 -1", "int"),
+            ("3 0 1 1.5", "float"),
+            ("3 0 1 3", "uint"),
+            ("3 0 1 1e30", "double"),
+            ("3 0 1 nan", "double"),
+            ("2.5 0 1 2", "int"),
+        ] {
+            assert!(matches!(
+                read_ply(with_face(face, index_type).as_bytes()),
+                Err(Error::InvalidMesh(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_negative_binary_indices() {
+        let mut bytes = b"ply\nformat binary_little_endian 1.0\nelement vertex 3\n\
+            property float x\nproperty float y\nproperty float z\nelement face 1\n\
+            property list uchar int vertex_indices\nend_header\n"
+            .to_vec();
+        for _ in 0..9 {
+            bytes.extend_from_slice(&0.0_f32.to_le_bytes());
+        }
+        bytes.push(3);
+        for index in [0, 1, -2_i32] {
+            bytes.extend_from_slice(&index.to_le_bytes());
+        }
+
+        assert!(matches!(
+            read_ply(bytes.as_slice()),
+            Err(Error::InvalidMesh(_))
+        ));
+    }
+}
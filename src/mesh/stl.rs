@@ -0,0 +1,234 @@
+use crate::{
+    error::{Error, Result},
+    math::affine::primitives::{Point, Vector},
+    mesh::triangle_mesh::Mesh,
+};
+use std::{
+    collections::BTreeMap,
+    io::{Read, Write},
+};
+
+const HEADER_SIZE: usize = 80;
+const TRIANGLE_SIZE: usize = 50;
+
+// STL stores unit face normals and unshared single precision vertices, so texture
+// coordinates, colours and vertex normals are lost
+
+fn unit_face_normal(mesh: &Mesh, triangle: usize) -> Vector {
+    let normal = mesh.face_normal(triangle);
+    if normal.length() > 0.0 {
+        normal.normalized()
+    } else {
+        normal
+    }
+}
+
+pub fn write_stl_ascii<W: Write>(mesh: &Mesh, name: &str, mut writer: W) -> Result<()> {
+    writeln!(writer, "solid {}", name)?;
+
+    for (index, triangle) in mesh.triangles().iter().enumerate() {
+        let normal = unit_face_normal(mesh, index);
+        writeln!(
+            writer,
+            "  facet normal {:e} {:e} {:e}",
+            normal.at(0),
+            normal.at(1),
+            normal.at(2)
+        )?;
+        writeln!(writer, "    outer loop")?;
+        for vertex in triangle.map(|vertex| mesh.vertices()[vertex]) {
+            writeln!(
+                writer,
+                "      vertex {:e} {:e} {:e}",
+                vertex.at(0),
+                vertex.at(1),
+                vertex.at(2)
+            )?;
+        }
+        writeln!(writer, "    endloop")?;
+        writeln!(writer, "  endfacet")?;
+    }
+
+    writeln!(writer, "endsolid {}", name)?;
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write_stl_binary<W: Write>(mesh: &Mesh, mut writer: W) -> Result<()> {
+    let count = u32::try_from(mesh.triangles().len()).map_err(|_| {
+        Error::InvalidMesh(format!(
+            "{} triangles do not fit in binary STL",
+            mesh.triangles().len()
+        ))
+    })?;
+
+    writer.write_all(&[0; HEADER_SIZE])?;
+    writer.write_all(&count.to_le_bytes())?;
+
+    for (index, triangle) in mesh.triangles().iter().enumerate() {
+        let normal = unit_face_normal(mesh, index);
+        let vertices = triangle.map(|vertex| Vector::to_point(mesh.vertices()[vertex]));
+
+        for vector in [normal, vertices[0], vertices[1], vertices[2]] {
+            for axis in 0..3 {
+                writer.write_all(&(vector.at(axis) as f32).to_le_bytes())?;
+            }
+        }
+        writer.write_all(&0u16.to_le_bytes())?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+// Merges vertices at exactly the same position, which restores the connectivity of
+// meshes written from indexed meshes
+fn indexed_mesh(corners: Vec<Point>) -> Result<Mesh> {
+    let mut indices = BTreeMap::new();
+    let mut vertices = Vec::new();
+
+    let corners: Vec<usize> = corners
+        .into_iter()
+        .map(|corner| {
+            let key = [0, 1, 2].map(|axis| corner.at(axis).to_bits());
+            *indices.entry(key).or_insert_with(|| {
+                vertices.push(corner);
+                vertices.len() - 1
+            })
+        })
+        .collect();
+
+    let triangles = corners
+        .chunks_exact(3)
+        .map(|corners| [corners[0], corners[1], corners[2]])
+        .collect();
+
+    Mesh::with_face_normals(vertices, triangles)
+}
+
+fn read_binary(bytes: &[u8]) -> Result<Mesh> {
+    let float = |offset: usize| {
+        let mut value = [0; 4];
+        value.copy_from_slice(&bytes[offset..offset + 4]);
+        f32::from_le_bytes(value) as f64
+    };
+
+    let count = (bytes.len() - HEADER_SIZE - 4) / TRIANGLE_SIZE;
+    let mut corners = Vec::with_capacity(3 * count);
+    for triangle in 0..count {
+        // Skips the stored normal, the winding is what orients the faces
+        let start = HEADER_SIZE + 4 + triangle * TRIANGLE_SIZE + 12;
+        for corner in 0..3 {
+            let offset = start + 12 * corner;
+            corners.push(Point::new(
+                float(offset),
+                float(offset + 4),
+                float(offset + 8),
+            ));
+        }
+    }
+
+    indexed_mesh(corners)
+}
+
+fn read_ascii(text: &str) -> Result<Mesh> {
+    let mut corners = Vec::new();
+
+    for (number, line) in text.lines().enumerate() {
+        let mut fields = line.split_whitespace();
+        if fields.next() != Some("vertex") {
+            continue;
+        }
+
+        let values = fields
+            .map(|field| field.parse::<f64>())
+            .collect::<core::result::Result<Vec<_>, _>>()
+            .ok()
+            .filter(|values| values.len() == 3)
+            .ok_or_else(|| {
+                Error::InvalidMesh(format!(
+                    "line {}: invalid vertex {}",
+                    number + 1,
+                    line.trim()
+                ))
+            })?;
+        corners.push(Point::new(values[0], values[1], values[2]));
+    }
+
+    if corners.len() % 3 != 0 {
+        return Err(Error::InvalidMesh(format!(
+            "{} vertices do not form triangles",
+            corners.len()
+        )));
+    }
+
+    indexed_mesh(corners)
+}
+
+// Detects the encoding by the size a binary file must have, since binary headers may
+// also start with "solid"
+pub fn read_stl<R: Read>(mut reader: R) -> Result<Mesh> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    if bytes.len() >= HEADER_SIZE + 4 {
+        let mut count = [0; 4];
+        count.copy_from_slice(&bytes[HEADER_SIZE..HEADER_SIZE + 4]);
+        let count = u32::from_le_bytes(count) as usize;
+
+        let size = count
+            .checked_mul(TRIANGLE_SIZE)
+            .and_then(|size| size.checked_add(HEADER_SIZE + 4));
+        if size == Some(bytes.len()) {
+            return read_binary(&bytes);
+        }
+    }
+
+    match core::str::from_utf8(&bytes) {
+        Ok(text) if text.trim_start().starts_with("solid") => read_ascii(text),
+        _ => Err(Error::InvalidMesh(String::from(
+            "neither binary STL nor ASCII STL",
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::triangle_mesh::tests::*;
+
+    #[test]
+    fn round_trips() {
+        let mesh = tetrahedron();
+
+        let mut ascii = Vec::new();
+        write_stl_ascii(&mesh, "tetrahedron", &mut ascii).unwrap();
+        assert_same(&read_stl(ascii.as_slice()).unwrap(), &mesh, 1e-15);
+
+        let mut binary = Vec::new();
+        write_stl_binary(&mesh, &mut binary).unwrap();
+        assert_eq!(binary.len(), HEADER_SIZE + 4 + 4 * TRIANGLE_SIZE);
+        assert_same(&read_stl(binary.as_slice()).unwrap(), &mesh, 1e-15);
+    }
+
+    #[test]
+    fn drops_what_it_cannot_store() {
+        let mut bytes = Vec::new();
+        write_stl_binary(&decorated_tetrahedron(), &mut bytes).unwrap();
+
+        assert_same(&read_stl(bytes.as_slice()).unwrap(), &tetrahedron(), 1e-15);
+    }
+
+    #[test]
+    fn rejects_incomplete_triangles() {
+        let text = "solid broken\nvertex 0 0 0\nvertex 1 0 0\nendsolid broken\n";
+        assert!(matches!(
+            read_stl(text.as_bytes()),
+            Err(Error::InvalidMesh(_))
+        ));
+        assert!(matches!(
+            read_stl(&b"neither"[..]),
+            Err(Error::InvalidMesh(_))
+        ));
+    }
+}
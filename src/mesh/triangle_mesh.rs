@@ -0,0 +1,268 @@
+use crate::{
+    error::{Error, Result},
+    math::affine::primitives::{Point, Vector},
+    primitives::color::Color,
+};
+use alloc::{collections::BTreeMap, format, vec, vec::Vec};
+
+// Indexed triangle mesh with one normal per vertex, and optionally texture coordinates
+// and colours per vertex. Triangles are counterclockwise seen from the side the normals
+// point to.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mesh {
+    vertices: Vec<Point>,
+    normals: Vec<Vector>,
+    triangles: Vec<[usize; 3]>,
+    uvs: Option<Vec<[f64; 2]>>,
+    colors: Option<Vec<Color>>,
+}
+
+impl Mesh {
+    pub fn new(vertices: Vec<Point>, normals: Vec<Vector>, triangles: Vec<[usize; 3]>) -> Mesh {
+        Self::try_new(vertices, normals, triangles).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_new(
+        vertices: Vec<Point>,
+        normals: Vec<Vector>,
+        triangles: Vec<[usize; 3]>,
+    ) -> Result<Mesh> {
+        check_length("normals", normals.len(), vertices.len())?;
+        if let Some(index) = triangles
+            .iter()
+            .flatten()
+            .find(|&&index| index >= vertices.len())
+        {
+            return Err(Error::InvalidMesh(format!(
+                "vertex index {} out of range for {} vertices",
+                index,
+                vertices.len()
+            )));
+        }
+
+        Ok(Mesh {
+            vertices,
+            normals,
+            triangles,
+            uvs: None,
+            colors: None,
+        })
+    }
+
+    // Vertex normals averaged from the faces around each vertex, weighted by area
+    pub fn with_face_normals(vertices: Vec<Point>, triangles: Vec<[usize; 3]>) -> Result<Mesh> {
+        let mut mesh = Self::try_new(
+            vertices.clone(),
+            vec![Vector::new(0.0, 0.0, 0.0); vertices.len()],
+            triangles,
+        )?;
+
+        for triangle in 0..mesh.triangles.len() {
+            let normal = mesh.face_normal(triangle);
+            for vertex in mesh.triangles[triangle] {
+                mesh.normals[vertex] = mesh.normals[vertex] + normal;
+            }
+        }
+        for normal in &mut mesh.normals {
+            if normal.length() > 0.0 {
+                *normal = normal.normalized();
+            }
+        }
+
+        Ok(mesh)
+    }
+
+    pub fn with_uvs(self, uvs: Vec<[f64; 2]>) -> Result<Mesh> {
+        check_length("texture coordinates", uvs.len(), self.vertices.len())?;
+        Ok(Mesh {
+            uvs: Some(uvs),
+            ..self
+        })
+    }
+
+    pub fn with_colors(self, colors: Vec<Color>) -> Result<Mesh> {
+        check_length("colours", colors.len(), self.vertices.len())?;
+        Ok(Mesh {
+            colors: Some(colors),
+            ..self
+        })
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    pub fn normals(&self) -> &[Vector] {
+        &self.normals
+    }
+
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    pub fn uvs(&self) -> Option<&[[f64; 2]]> {
+        self.uvs.as_deref()
+    }
+
+    pub fn colors(&self) -> Option<&[Color]> {
+        self.colors.as_deref()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    // Unnormalized, with the length of twice the area
+    pub fn face_normal(&self, triangle: usize) -> Vector {
+        let [a, b, c] = self.triangles[triangle].map(|index| self.vertices[index]);
+        (b - a).cross(c - a)
+    }
+
+    pub fn area(&self) -> f64 {
+        (0..self.triangles.len())
+            .map(|triangle| 0.5 * self.face_normal(triangle).length())
+            .sum()
+    }
+
+    // Signed volume enclosed by the mesh by the divergence theorem, only meaningful
+    // for watertight meshes
+    pub fn volume(&self) -> f64 {
+        self.triangles
+            .iter()
+            .map(|triangle| {
+                let [a, b, c] = triangle.map(|index| Vector::to_point(self.vertices[index]));
+                a * b.cross(c) / 6.0
+            })
+            .sum()
+    }
+
+    // Every edge is shared by exactly two triangles which traverse it in opposite
+    // directions, so the mesh bounds a solid
+    pub fn is_watertight(&self) -> bool {
+        let mut edges = BTreeMap::new();
+
+        for triangle in &self.triangles {
+            for i in 0..3 {
+                let (from, to) = (triangle[i], triangle[(i + 1) % 3]);
+                *edges.entry((from, to)).or_insert(0) += 1;
+            }
+        }
+
+        edges
+            .iter()
+            .all(|(&(from, to), &count)| count == 1 && edges.get(&(to, from)) == Some(&1))
+    }
+}
+
+fn check_length(attribute: &str, length: usize, vertices: usize) -> Result<()> {
+    if length != vertices {
+        return Err(Error::InvalidMesh(format!(
+            "{} {} for {} vertices",
+            length, attribute, vertices
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    // A closed tetrahedron whose vertices first appear in order on the triangles, with
+    // values that single precision formats store exactly
+    pub(crate) fn tetrahedron() -> Mesh {
+        let vertices = vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 0.0, 1.0),
+        ];
+        let triangles = vec![[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]];
+
+        Mesh::with_face_normals(vertices, triangles).unwrap()
+    }
+
+    pub(crate) fn decorated_tetrahedron() -> Mesh {
+        let gray = |value: f32| Color {
+            r: value,
+            g: 1.0 - value,
+            b: 0.2,
+            a: 1.0,
+        };
+
+        tetrahedron()
+            .with_uvs(vec![[0.0, 0.0], [1.0, 0.0], [0.5, 1.0], [0.25, 0.75]])
+            .unwrap()
+            .with_colors(vec![gray(0.0), gray(1.0), gray(0.2), gray(0.6)])
+            .unwrap()
+    }
+
+    pub(crate) fn assert_same(read: &Mesh, written: &Mesh, tolerance: f64) {
+        assert_eq!(read.vertices().len(), written.vertices().len());
+        for (read, written) in read.vertices().iter().zip(written.vertices()) {
+            assert_eq!((*read - *written).length(), 0.0);
+        }
+        assert_eq!(read.triangles(), written.triangles());
+        for (read, written) in read.normals().iter().zip(written.normals()) {
+            assert!((*read - *written).length() <= tolerance);
+        }
+
+        assert_eq!(read.uvs().is_some(), written.uvs().is_some());
+        for (read, written) in read
+            .uvs()
+            .unwrap_or(&[])
+            .iter()
+            .zip(written.uvs().unwrap_or(&[]))
+        {
+            assert!((read[0] - written[0]).abs() <= tolerance);
+            assert!((read[1] - written[1]).abs() <= tolerance);
+        }
+
+        assert_eq!(read.colors().is_some(), written.colors().is_some());
+        let channels = |color: &Color| [color.r, color.g, color.b, color.a];
+        for (read, written) in read
+            .colors()
+            .unwrap_or(&[])
+            .iter()
+            .zip(written.colors().unwrap_or(&[]))
+        {
+            for (read, written) in channels(read).into_iter().zip(channels(written)) {
+                assert!((read - written).abs() <= 1.0 / 510.0);
+            }
+        }
+    }
+
+    #[test]
+    fn closed_meshes_are_watertight_and_measured() {
+        let mesh = tetrahedron();
+
+        assert!(mesh.is_watertight());
+        assert!((mesh.volume() - 1.0 / 6.0).abs() < 1e-15);
+        assert!((mesh.area() - (1.5 + 0.75_f64.sqrt())).abs() < 1e-15);
+
+        let open =
+            Mesh::with_face_normals(mesh.vertices().to_vec(), mesh.triangles()[1..].to_vec())
+                .unwrap();
+        assert!(!open.is_watertight());
+    }
+
+    #[test]
+    fn rejects_inconsistent_attributes() {
+        let mesh = tetrahedron();
+
+        assert!(matches!(
+            Mesh::try_new(mesh.vertices().to_vec(), Vec::new(), Vec::new()),
+            Err(Error::InvalidMesh(_))
+        ));
+        assert!(matches!(
+            Mesh::with_face_normals(mesh.vertices().to_vec(), vec![[0, 1, 4]]),
+            Err(Error::InvalidMesh(_))
+        ));
+        assert!(matches!(
+            mesh.with_uvs(vec![[0.0, 0.0]]),
+            Err(Error::InvalidMesh(_))
+        ));
+    }
+}
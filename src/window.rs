@@ -1,4 +1,7 @@
-use crate::primitives::color::Color;
+use crate::{
+    error::{Error, Result},
+    primitives::color::Color,
+};
 
 use glow::HasContext;
 use glutin::event_loop::EventLoop;
@@ -14,6 +17,10 @@ pub struct Window {
 
 impl Window {
     pub fn new(title: &str, width: u32, height: u32) -> (Window, EventLoop<()>) {
+        Self::try_new(title, width, height).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_new(title: &str, width: u32, height: u32) -> Result<(Window, EventLoop<()>)> {
         let event_loop = glutin::event_loop::EventLoop::new();
         let window = glutin::window::WindowBuilder::new()
             .with_title(title)
@@ -22,9 +29,10 @@ impl Window {
         let window = glutin::ContextBuilder::new()
             .with_vsync(true)
             .build_windowed(window, &event_loop)
-            .unwrap();
+            .map_err(|error| Error::Window(error.to_string()))?;
 
-        let windowed_context = unsafe { window.make_current() }.unwrap();
+        let windowed_context = unsafe { window.make_current() }
+            .map_err(|(_, error)| Error::Window(error.to_string()))?;
 
         let (mut imgui_context, winit_platform) = Self::create_imgui_context(&windowed_context);
 
@@ -32,10 +40,10 @@ impl Window {
             glow::Context::from_loader_function(|s| windowed_context.get_proc_address(s).cast())
         };
 
-        let imgui_renderer =
-            imgui_glow_renderer::AutoRenderer::initialize(gl, &mut imgui_context).unwrap();
+        let imgui_renderer = imgui_glow_renderer::AutoRenderer::initialize(gl, &mut imgui_context)
+            .map_err(|error| Error::Renderer(error.to_string()))?;
 
-        (
+        Ok((
             Window {
                 windowed_context,
                 imgui_renderer,
@@ -43,7 +51,7 @@ impl Window {
                 imgui_context,
             },
             event_loop,
-        )
+        ))
     }
 
     fn create_imgui_context(
@@ -84,14 +92,15 @@ impl Window {
         self.imgui_context.io_mut().update_delta_time(duration);
     }
 
-    pub fn request_redraw(&mut self) {
+    pub fn request_redraw(&mut self) -> Result<()> {
         self.winit_platform
             .prepare_frame(self.imgui_context.io_mut(), self.windowed_context.window())
-            .unwrap();
+            .map_err(|error| Error::Window(error.to_string()))?;
         self.windowed_context.window().request_redraw();
+        Ok(())
     }
 
-    pub fn render<F: FnOnce(&mut imgui::Ui)>(&mut self, build_ui: F) {
+    pub fn render<F: FnOnce(&mut imgui::Ui)>(&mut self, build_ui: F) -> Result<()> {
         let ui = self.imgui_context.frame();
         build_ui(ui);
 
@@ -99,8 +108,12 @@ impl Window {
             .prepare_render(ui, self.windowed_context.window());
         let draw_data = self.imgui_context.render();
 
-        self.imgui_renderer.render(draw_data).unwrap();
-        self.windowed_context.swap_buffers().unwrap();
+        self.imgui_renderer
+            .render(draw_data)
+            .map_err(Error::Renderer)?;
+        self.windowed_context
+            .swap_buffers()
+            .map_err(|error| Error::Window(error.to_string()))
     }
 
     pub fn imgui_using_mouse(&self) -> bool {